@@ -9,10 +9,17 @@
 
 use core::panic;
 
+mod parse;
+
 use proc_macro::TokenStream;
 
-use quote::quote;
-use syn::{DeriveInput, parse_macro_input};
+use proc_macro2::Span;
+use quote::{format_ident, quote, quote_spanned};
+use syn::meta::ParseNestedMeta;
+use syn::{
+    DeriveInput, Expr, FnArg, Ident, ItemFn, LitInt, LitStr, Pat, PatIdent, PatType, Path,
+    parse_macro_input, parse_quote,
+};
 
 #[proc_macro_derive(VoidState, attributes(void))]
 pub fn derive_void(input: TokenStream) -> TokenStream {
@@ -50,3 +57,265 @@ pub fn derive_void(input: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+/// Implements `FromStr` using a pattern with a placeholder for each field, eg
+///
+/// ```ignore
+/// #[derive(AocParse)]
+/// #[aoc(pattern = "p={pos} v={vel}")]
+/// struct Robot {
+///     pos: Coord2D,
+///     vel: Coord2D,
+/// }
+/// ```
+///
+/// Each field is parsed with its own `FromStr`. Tuple fields are `{0}`, `{1}`
+/// etc, and literal braces are `{{` and `}}`. Enums need a pattern on every
+/// variant, and the first one that matches is used.
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn derive_aoc_parse(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input);
+    parse::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Which of a part's functions an attribute is registering
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Main,
+    Example,
+}
+
+#[derive(Default)]
+struct RegisterArgs {
+    part: Option<LitInt>,
+    params: Vec<(Ident, Expr)>,
+    with: Option<Path>,
+    no_example: bool,
+    slow: bool,
+    interactive: bool,
+    needs_terminal: bool,
+    requires_params: bool,
+    broken: Option<LitStr>,
+}
+
+impl RegisterArgs {
+    fn parse(&mut self, meta: ParseNestedMeta, kind: Kind) -> syn::Result<()> {
+        if meta.path.is_ident("part") {
+            let part: LitInt = meta.value()?.parse()?;
+            match part.base10_parse::<u8>()? {
+                1 | 2 => self.part = Some(part),
+                _ => return Err(syn::Error::new(part.span(), "part must be 1 or 2")),
+            }
+        } else if meta.path.is_ident("params") {
+            meta.parse_nested_meta(|param| {
+                let name = param.path.require_ident()?.clone();
+                if self.params.iter().any(|(other, _)| *other == name) {
+                    return Err(param.error("parameter given more than once"));
+                }
+                self.params.push((name, param.value()?.parse()?));
+                Ok(())
+            })?;
+        } else if meta.path.is_ident("with") {
+            self.with = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("no_example") && kind == Kind::Main {
+            self.no_example = true;
+        } else if meta.path.is_ident("slow") {
+            self.slow = true;
+        } else if meta.path.is_ident("interactive") {
+            self.interactive = true;
+        } else if meta.path.is_ident("needs_terminal") {
+            self.needs_terminal = true;
+        } else if meta.path.is_ident("requires_params") {
+            self.requires_params = true;
+        } else if meta.path.is_ident("broken") {
+            self.broken = Some(meta.value()?.parse()?);
+        } else {
+            return Err(meta.error("unsupported property"));
+        }
+        Ok(())
+    }
+}
+
+fn marker(part: u8, kind: Kind, span: Span) -> Ident {
+    let kind = match kind {
+        Kind::Main => "SOLUTION",
+        Kind::Example => "EXAMPLE",
+    };
+    format_ident!("__AOC_PART_{part}_{kind}", span = span)
+}
+
+/// Matches the values given in `params(...)` up with the function's arguments
+/// after the input, returning the `AoCParam` declarations and the expressions
+/// to pass as each argument
+fn function_params(func: &ItemFn, values: &[(Ident, Expr)]) -> syn::Result<(Vec<Expr>, Vec<Expr>)> {
+    let mut names = Vec::new();
+    let mut params = Vec::new();
+    let mut args = Vec::new();
+    for input in func.sig.inputs.iter().skip(1) {
+        let FnArg::Typed(PatType { pat, ty, .. }) = input else {
+            return Err(syn::Error::new_spanned(input, "solutions can't take self"));
+        };
+        let Pat::Ident(PatIdent { ident: name, .. }) = pat.as_ref() else {
+            return Err(syn::Error::new_spanned(
+                pat,
+                "parameters must be plain names",
+            ));
+        };
+        let Some((_, value)) = values.iter().find(|(other, _)| other == name) else {
+            return Err(syn::Error::new_spanned(
+                name,
+                format!("no value given for parameter `{name}`, add it to `params(...)`"),
+            ));
+        };
+        names.push(name);
+        let label = name.to_string();
+        params.push(parse_quote!(crate::AoCParam {
+            name: #label,
+            default: || {
+                let value: #ty = #value;
+                value.to_string()
+            },
+        }));
+        args.push(parse_quote!(crate::utils::params::get::<#ty>(#label, #value)));
+    }
+    if let Some((name, _)) = values.iter().find(|(name, _)| !names.contains(&name)) {
+        return Err(syn::Error::new_spanned(
+            name,
+            format!("`{}` has no parameter called `{name}`", func.sig.ident),
+        ));
+    }
+    Ok((params, args))
+}
+
+fn register(args: TokenStream, input: TokenStream, kind: Kind) -> TokenStream {
+    let mut reg_args = RegisterArgs::default();
+    let parser = syn::meta::parser(|meta| reg_args.parse(meta, kind));
+    parse_macro_input!(args with parser);
+    let func: ItemFn = parse_macro_input!(input);
+
+    // Keep the function around when things go wrong so the only errors are
+    // about the attribute rather than everything the function uses
+    let fail = |err: syn::Error| -> TokenStream {
+        let err = err.to_compile_error();
+        quote!(#err #func).into()
+    };
+
+    let Some(part_lit) = reg_args.part else {
+        return fail(syn::Error::new(
+            func.sig.ident.span(),
+            "missing `part = 1` or `part = 2`",
+        ));
+    };
+    let part: u8 = part_lit.base10_parse().unwrap();
+    let span = part_lit.span();
+
+    let part_variant = format_ident!("Part{part}");
+    let ident = &func.sig.ident;
+    let (params, args) = match function_params(&func, &reg_args.params) {
+        Ok(ret) => ret,
+        Err(err) => return fail(err),
+    };
+    // Solutions can return anything that turns into an Answer
+    let mut call: Expr = parse_quote!(|data| #ident(data, #(#args?),*).map(crate::Answer::from));
+    if let Some(with) = &reg_args.with {
+        call = parse_quote!(|data| #with(data, #call, crate::Part::#part_variant));
+    }
+
+    let RegisterArgs {
+        slow,
+        interactive,
+        needs_terminal,
+        requires_params,
+        ..
+    } = reg_args;
+    let broken = match &reg_args.broken {
+        Some(reason) => quote!(Some(#reason)),
+        None => quote!(None),
+    };
+    let meta = quote! {
+        crate::AoCMeta {
+            slow: #slow,
+            interactive: #interactive,
+            needs_terminal: #needs_terminal,
+            requires_params: #requires_params,
+            broken: #broken,
+        }
+    };
+    let submit = |kind: Ident, call: &Expr, params: &[Expr]| {
+        quote! {
+            inventory::submit!(crate::AoCSolution {
+                module: module_path!(),
+                part: crate::Part::#part_variant,
+                kind: crate::SolutionKind::#kind,
+                func: #call,
+                params: &[#(#params),*],
+                meta: #meta,
+            });
+        }
+    };
+
+    // Every registration defines a marker constant so that registering the
+    // same thing twice is a compile error, and anything that depends on
+    // another registration refers to its marker so forgetting it is too
+    let this_marker = marker(part, kind, span);
+    let requires = match (kind, part) {
+        (Kind::Main, 1) => None,
+        (Kind::Main, _) => Some(marker(1, Kind::Main, span)),
+        (Kind::Example, part) => Some(marker(part, Kind::Main, span)),
+    }
+    .map(|required| quote_spanned!(span=> const _: () = #required;));
+
+    let mut registrations = match kind {
+        Kind::Main => submit(format_ident!("Main"), &call, &params),
+        Kind::Example => submit(format_ident!("Example"), &call, &params),
+    };
+    let mut no_example_marker = None;
+    if reg_args.no_example {
+        registrations.extend(submit(
+            format_ident!("Example"),
+            &parse_quote!(crate::no_example),
+            &[],
+        ));
+        no_example_marker = Some(marker(part, Kind::Example, span));
+    }
+    let markers = [Some(this_marker), no_example_marker].into_iter().flatten();
+
+    quote! {
+        #func
+        #(
+            #[allow(dead_code)]
+            const #markers: () = ();
+        )*
+        #requires
+        #registrations
+    }
+    .into()
+}
+
+/// Registers a function as the solution to one part of the day the module is
+/// for, eg `#[solution(part = 1)]`.
+///
+/// * `params(name = value, ...)` gives the default for each of the function's
+///   arguments after the input, which can be overridden with `--param`
+/// * `with = path` wraps the call, eg `crate::multi_line_example`, which is
+///   given the input, the function and the part
+/// * `no_example` marks the part as having no usable example
+///
+/// Parts that shouldn't be run unattended can be marked as `slow`,
+/// `interactive`, `needs_terminal`, `requires_params` or
+/// `broken = "reason"`.
+#[proc_macro_attribute]
+pub fn solution(args: TokenStream, input: TokenStream) -> TokenStream {
+    register(args, input, Kind::Main)
+}
+
+/// Registers a function to run against the example input for one part, in
+/// place of the solution. Takes the same properties as `#[solution]`, apart
+/// from `no_example`. Anything the solution is marked as applies to the
+/// example too.
+#[proc_macro_attribute]
+pub fn example(args: TokenStream, input: TokenStream) -> TokenStream {
+    register(args, input, Kind::Example)
+}
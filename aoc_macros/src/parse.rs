@@ -0,0 +1,248 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, LitStr, Member};
+
+/// The characters `regex::escape` would escape
+const REGEX_META: &str = r"\.+*?()|[]{}^$#&-~";
+
+/// A pattern like `p={x},{y}`, turned into a regex with a capture group for
+/// each field
+struct Pattern {
+    text: LitStr,
+    regex: String,
+    fields: Vec<Member>,
+}
+
+impl Pattern {
+    fn parse(text: LitStr) -> syn::Result<Self> {
+        let mut regex = String::from("^");
+        let mut fields = Vec::new();
+        let value = text.value();
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            let literal = match c {
+                '{' if chars.peek() == Some(&'{') => chars.next().unwrap(),
+                '}' if chars.peek() == Some(&'}') => chars.next().unwrap(),
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => name.push(c),
+                            None => {
+                                return Err(syn::Error::new(
+                                    text.span(),
+                                    format!("unclosed `{{{name}`, use `{{{{` for a literal `{{`"),
+                                ));
+                            }
+                        }
+                    }
+                    let field: Member = syn::parse_str(name.trim()).map_err(|_| {
+                        syn::Error::new(text.span(), format!("`{{{name}}}` isn't a field name"))
+                    })?;
+                    if fields.contains(&field) {
+                        return Err(syn::Error::new(
+                            text.span(),
+                            format!("`{{{name}}}` is in the pattern more than once"),
+                        ));
+                    }
+                    fields.push(field);
+                    regex.push_str("(.*?)");
+                    continue;
+                }
+                '}' => {
+                    return Err(syn::Error::new(
+                        text.span(),
+                        "unmatched `}`, use `}}` for a literal one",
+                    ));
+                }
+                c => c,
+            };
+            if REGEX_META.contains(literal) {
+                regex.push('\\');
+            }
+            regex.push(literal);
+        }
+        regex.push('$');
+        Ok(Self {
+            text,
+            regex,
+            fields,
+        })
+    }
+}
+
+fn find_pattern(attrs: &[Attribute], span: proc_macro2::Span) -> syn::Result<Pattern> {
+    let mut pattern = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("aoc")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("pattern") {
+                pattern = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported property"))
+            }
+        })?;
+    }
+    let pattern =
+        pattern.ok_or_else(|| syn::Error::new(span, "missing `#[aoc(pattern = \"...\")]`"))?;
+    Pattern::parse(pattern)
+}
+
+/// Builds the code that returns the type or variant with its fields filled in
+/// from the pattern's captures, if the input matches. If one of the fields
+/// won't parse then the error is kept and the next pattern gets a go.
+fn construct(
+    path: TokenStream,
+    label: &str,
+    fields: &Fields,
+    pattern: &Pattern,
+    re: &Ident,
+) -> syn::Result<TokenStream> {
+    let members: Vec<Member> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(i.into()),
+        })
+        .collect();
+    if let Some(unknown) = pattern.fields.iter().find(|field| !members.contains(field)) {
+        return Err(syn::Error::new(
+            pattern.text.span(),
+            format!("`{label}` has no field `{}`", quote!(#unknown)),
+        ));
+    }
+    if let Some(missing) = members.iter().find(|field| !pattern.fields.contains(field)) {
+        return Err(syn::Error::new(
+            pattern.text.span(),
+            format!("the pattern doesn't say where `{}` is", quote!(#missing)),
+        ));
+    }
+
+    let regex = &pattern.regex;
+    let values = members.iter().map(|member| {
+        let index = pattern
+            .fields
+            .iter()
+            .position(|field| field == member)
+            .unwrap()
+            + 1;
+        let name = format!("{label}.{}", quote!(#member));
+        quote!(#member: crate::utils::pattern::parse_capture(&captures, #index, #name, s)?)
+    });
+    Ok(quote! {
+        ::lazy_static::lazy_static! {
+            static ref #re: ::regex::Regex =
+                ::regex::Regex::new(#regex).expect("derived patterns are always valid");
+        }
+        if let Some(captures) = #re.captures(s) {
+            let ret = (|| -> crate::AoCResult<Self> { Ok(#path { #(#values),* }) })();
+            match ret {
+                Ok(ret) => return Ok(ret),
+                Err(err) => last_error = Some(err),
+            }
+        }
+    })
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let name = ident.to_string();
+    let (matchers, description) = match &input.data {
+        Data::Struct(data) => {
+            let pattern = find_pattern(&input.attrs, ident.span())?;
+            let matcher = construct(
+                quote!(Self),
+                &name,
+                &data.fields,
+                &pattern,
+                &format_ident!("RE"),
+            )?;
+            let description = format!("the pattern {:?}", pattern.text.value());
+            (vec![matcher], description)
+        }
+        Data::Enum(data) => {
+            let matchers = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| {
+                    let pattern = find_pattern(&variant.attrs, variant.ident.span())?;
+                    let variant_ident = &variant.ident;
+                    construct(
+                        quote!(Self::#variant_ident),
+                        &format!("{name}::{variant_ident}"),
+                        &variant.fields,
+                        &pattern,
+                        &format_ident!("RE_{i}"),
+                    )
+                })
+                .collect::<syn::Result<_>>()?;
+            (matchers, format!("any of the patterns for {name}"))
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(ident.span(), "unions can't be parsed"));
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #ident #ty_generics #where_clause {
+            type Err = crate::AoCError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut last_error: Option<crate::AoCError> = None;
+                #({ #matchers })*
+                Err(last_error.unwrap_or_else(|| {
+                    crate::AoCError::new(format!("{s:?} doesn't match {}", #description))
+                }))
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use proc_macro2::Span;
+    use syn::LitStr;
+
+    use super::Pattern;
+
+    fn parse(pattern: &str) -> syn::Result<Pattern> {
+        Pattern::parse(LitStr::new(pattern, Span::call_site()))
+    }
+
+    #[test]
+    fn test_fields() {
+        let pattern = parse("p={pos} v={vel}").unwrap();
+        assert_eq!(pattern.regex, r"^p=(.*?) v=(.*?)$");
+        assert_eq!(pattern.fields.len(), 2);
+    }
+
+    #[test]
+    fn test_literal_braces() {
+        let pattern = parse("{{{0}}}").unwrap();
+        assert_eq!(pattern.regex, r"^\{(.*?)\}$");
+    }
+
+    #[test]
+    fn test_unclosed() {
+        let err = parse("p={pos").err().unwrap();
+        assert!(err.to_string().contains("unclosed `{pos`"), "{err}");
+    }
+
+    #[test]
+    fn test_unmatched() {
+        assert!(parse("p=pos}").is_err());
+    }
+}
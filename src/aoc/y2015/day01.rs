@@ -7,8 +7,11 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::{example, solution};
 use itertools::{FoldWhile, Itertools};
 
+#[solution(part = 1)]
+#[example(part = 1, with = crate::multi_line_example)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(data
         .next()
@@ -23,6 +26,8 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
         .to_string())
 }
 
+#[solution(part = 2)]
+#[example(part = 2, with = crate::multi_line_example)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(data
         .next()
@@ -46,16 +51,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
         .0
         .to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2015",
-    day: "1",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::multi_line_example(data, part_1),
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| crate::multi_line_example(data, part_2),
-    }),
-});
@@ -9,10 +9,13 @@
 
 use std::collections::HashSet;
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
 use crate::{Coord2D, Direction};
 
+#[solution(part = 1)]
+#[example(part = 1, with = crate::multi_line_example)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let line = data.next().unwrap();
 
@@ -29,6 +32,8 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(seen.len().to_string())
 }
 
+#[solution(part = 2)]
+#[example(part = 2, with = crate::multi_line_example)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let line = data.next().unwrap();
 
@@ -53,16 +58,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(seen.len().to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2015",
-    day: "3",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::multi_line_example(data, part_1),
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| crate::multi_line_example(data, part_2),
-    }),
-});
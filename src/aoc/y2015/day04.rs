@@ -7,8 +7,11 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::{example, solution};
 use md5::{Digest, Md5};
 
+#[solution(part = 1, slow)]
+#[example(part = 1, with = crate::multi_line_example)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let input = data.next().unwrap();
     let mut hasher = Md5::new();
@@ -23,6 +26,8 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     }
     unreachable!()
 }
+#[solution(part = 2, slow)]
+#[example(part = 2, with = crate::multi_line_example)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let input = data.next().unwrap();
     let mut hasher = Md5::new();
@@ -37,16 +42,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     }
     unreachable!()
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2015",
-    day: "4",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::multi_line_example(data, part_1),
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| crate::multi_line_example(data, part_2),
-    }),
-});
@@ -9,8 +9,10 @@
 
 use std::collections::HashSet;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let ret = data
         .filter(|line| {
@@ -38,13 +40,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
         .count();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2015",
-    day: "5",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
@@ -9,6 +9,7 @@
 
 use std::str::FromStr;
 
+use aoc_macros::solution;
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -74,6 +75,7 @@ impl FromStr for Command {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut grid = Grid::<bool>::new_filled(1000, 1000, false);
     for command in data.map(|line| line.parse()) {
@@ -88,6 +90,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(grid.into_iter().filter(|(_, v)| *v).count().to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut grid = Grid::<u32>::new_filled(1000, 1000, 0);
     for command in data.map(|line| line.parse()) {
@@ -105,16 +108,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .sum::<u64>()
         .to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2015",
-    day: "6",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -12,9 +12,8 @@ use std::{
     str::FromStr,
 };
 
+use aoc_macros::{AocParse, solution};
 use itertools::Itertools;
-use lazy_static::lazy_static;
-use regex::Regex;
 
 use crate::AoCError;
 
@@ -53,61 +52,29 @@ impl Input {
     }
 }
 
+#[derive(AocParse)]
 enum GateAction {
+    #[aoc(pattern = "{0} AND {1}")]
     And(Input, Input),
+    #[aoc(pattern = "{0} OR {1}")]
     Or(Input, Input),
+    #[aoc(pattern = "{0} RSHIFT {1}")]
     RShift(Input, Input),
+    #[aoc(pattern = "{0} LSHIFT {1}")]
     LShift(Input, Input),
+    #[aoc(pattern = "NOT {0}")]
     Not(Input),
+    #[aoc(pattern = "{0}")]
     Const(Input),
 }
 
-impl FromStr for GateAction {
-    type Err = AoCError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        lazy_static! {
-            static ref NOT_RE: Regex = Regex::new(r"^NOT (.+)$").unwrap();
-            static ref GATE_RE: Regex = Regex::new(r"^(.+) (AND|OR|RSHIFT|LSHIFT) (.+)$").unwrap();
-        }
-
-        if let Some(matches) = NOT_RE.captures(s) {
-            Ok(Self::Not(matches[1].parse()?))
-        } else if let Some(matches) = GATE_RE.captures(s) {
-            let a: Input = matches[1].parse()?;
-            let b: Input = matches[3].parse()?;
-            Ok(match &matches[2] {
-                "AND" => Self::And,
-                "OR" => Self::Or,
-                "RSHIFT" => Self::RShift,
-                "LSHIFT" => Self::LShift,
-                wat => unreachable!("mystery gate {wat}"),
-            }(a, b))
-        } else {
-            s.parse().map(Self::Const)
-        }
-    }
-}
-
+#[derive(AocParse)]
+#[aoc(pattern = "{action} -> {output}")]
 struct Gate {
     action: GateAction,
     output: String,
 }
 
-impl FromStr for Gate {
-    type Err = AoCError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (action, output) = s
-            .split_once(" -> ")
-            .ok_or(AoCError::new(format!("Malformed gate definition {s}")))?;
-        Ok(Self {
-            action: action.parse()?,
-            output: output.to_owned(),
-        })
-    }
-}
-
 impl Gate {
     fn get_inputs(&self) -> Vec<String> {
         match &self.action {
@@ -192,6 +159,7 @@ fn drain_updates(
     }
 }
 
+#[solution(part = 1, no_example)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let gates: Vec<Gate> = data.map(|line| line.parse()).try_collect()?;
     let mut wire_data: HashMap<String, WireValue> = gates
@@ -214,6 +182,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2, no_example)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let gates: Vec<Gate> = data.map(|line| line.parse()).try_collect()?;
     let mut wire_data: HashMap<String, WireValue> = gates
@@ -243,16 +212,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .expect("a must have a value");
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2015",
-    day: "7",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: crate::no_example
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: crate::no_example
-    }),
-});
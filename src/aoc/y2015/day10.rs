@@ -7,6 +7,8 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::{example, solution};
+
 fn look_and_say(line: String) -> String {
     let mut ret = String::new();
 
@@ -27,6 +29,7 @@ fn look_and_say(line: String) -> String {
     ret
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut line = data.next().unwrap();
     for _ in 0..40 {
@@ -35,6 +38,7 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(line.len().to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut line = data.next().unwrap();
     for _ in 0..50 {
@@ -42,20 +46,8 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(line.len().to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2015",
-    day: "10",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| {
-            crate::multi_line_example(data, |mut lines| Ok(look_and_say(lines.next().unwrap())))
-        }
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| {
-            crate::multi_line_example(data, |mut lines| Ok(look_and_say(lines.next().unwrap())))
-        }
-    }),
-});
+#[example(part = 1, with = crate::multi_line_example)]
+#[example(part = 2, with = crate::multi_line_example)]
+fn look_and_say_example(mut data: crate::DataIn) -> crate::AoCResult<String> {
+    Ok(look_and_say(data.next().unwrap()))
+}
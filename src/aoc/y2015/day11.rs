@@ -9,6 +9,7 @@
 
 use std::collections::HashSet;
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
 fn validate_password(password: &[char]) -> bool {
@@ -42,6 +43,8 @@ fn increment_password(mut password: Vec<char>) -> Vec<char> {
     password
 }
 
+#[solution(part = 1)]
+#[example(part = 1, with = crate::multi_line_example)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut password = data.next().unwrap().chars().collect_vec();
     while !validate_password(&password) {
@@ -50,6 +53,8 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(password.into_iter().collect())
 }
 
+#[solution(part = 2)]
+#[example(part = 2, with = crate::multi_line_example)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut password = data.next().unwrap().chars().collect_vec();
     while !validate_password(&password) {
@@ -62,19 +67,6 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(password.into_iter().collect())
 }
 
-inventory::submit!(crate::AoCDay {
-    year: "2015",
-    day: "11",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::multi_line_example(data, part_1)
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| crate::multi_line_example(data, part_2)
-    }),
-});
-
 #[cfg(test)]
 mod test_validate_password {
     use itertools::Itertools;
@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::{example, solution};
 use serde_json::Value as JSONValue;
 
 fn recursive_sum_part_1(value: JSONValue) -> i64 {
@@ -22,6 +23,8 @@ fn recursive_sum_part_1(value: JSONValue) -> i64 {
     }
 }
 
+#[solution(part = 1)]
+#[example(part = 1, with = crate::multi_line_example)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let line = data.next().unwrap();
     let value = serde_json::from_str(&line).unwrap();
@@ -47,22 +50,11 @@ fn recursive_sum_part_2(value: JSONValue) -> i64 {
     }
 }
 
+#[solution(part = 2)]
+#[example(part = 2, with = crate::multi_line_example)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let line = data.next().unwrap();
     let value = serde_json::from_str(&line).unwrap();
     let ret = recursive_sum_part_2(value);
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2015",
-    day: "12",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::multi_line_example(data, part_1),
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| crate::multi_line_example(data, part_2),
-    }),
-});
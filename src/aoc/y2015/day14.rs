@@ -9,6 +9,7 @@
 
 use std::str::FromStr;
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use num::Integer;
@@ -126,6 +127,8 @@ impl FromStr for Reindeer {
     }
 }
 
+#[solution(part = 1, params(time = 2503))]
+#[example(part = 1, params(time = 1000))]
 pub fn part_1(data: crate::DataIn, time: usize) -> crate::AoCResult<String> {
     let reindeer: Vec<Reindeer> = data.map(|line| line.parse()).try_collect()?;
 
@@ -141,6 +144,8 @@ pub fn part_1(data: crate::DataIn, time: usize) -> crate::AoCResult<String> {
         .ok_or(AoCError::new("No reindeer??"))
 }
 
+#[solution(part = 2, params(time = 2503))]
+#[example(part = 2, params(time = 1000))]
 pub fn part_2(data: crate::DataIn, time: usize) -> crate::AoCResult<String> {
     let mut reindeer: Vec<Reindeer> = data.map(|line| line.parse()).try_collect()?;
 
@@ -169,16 +174,3 @@ pub fn part_2(data: crate::DataIn, time: usize) -> crate::AoCResult<String> {
         .unwrap()
         .to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2015",
-    day: "14",
-    part_1: crate::AoCPart {
-        main: |data| part_1(data, 2503),
-        example: |data| part_1(data, 1000),
-    },
-    part_2: Some(crate::AoCPart {
-        main: |data| part_2(data, 2503),
-        example: |data| part_2(data, 1000),
-    }),
-});
@@ -9,8 +9,11 @@
 
 use std::collections::HashSet;
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
+#[solution(part = 1)]
+#[example(part = 1, with = crate::partitioned_example)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let ret: i64 = data
         .map(|line| line.parse())
@@ -20,6 +23,8 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
+#[example(part = 2, with = crate::partitioned_example)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let changes: Vec<i64> = data.map(|line| line.parse()).try_collect()?;
 
@@ -33,16 +38,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     unreachable!()
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2018",
-    day: "1",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::partitioned_example(data, part_1)
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| crate::partitioned_example(data, part_2)
-    }),
-});
@@ -7,8 +7,10 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut twos = 0;
     let mut threes = 0;
@@ -27,6 +29,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     for (line1, line2) in data.sorted().tuple_windows() {
         let mut beep = false;
@@ -53,16 +56,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     unreachable!()
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2018",
-    day: "2",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -65,6 +66,7 @@ fn griddle(claims: &[Claim]) -> InfGrid<GridState> {
     grid
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let claims: Vec<_> = data.map(|line| line.parse()).try_collect()?;
     let grid = griddle(&claims);
@@ -75,6 +77,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let claims: Vec<_> = data.map(|line| line.parse()).try_collect()?;
     let grid = griddle(&claims);
@@ -89,16 +92,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .unwrap();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2018",
-    day: "3",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
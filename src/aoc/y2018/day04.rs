@@ -9,6 +9,7 @@
 
 use std::collections::HashMap;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -56,6 +57,7 @@ fn parse_guards(data: crate::DataIn) -> AoCResult<Vec<GuardData>> {
     Ok(guards.into_iter().collect_vec())
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let guards = parse_guards(data)?;
     let sleepiest_soldier = guards
@@ -76,6 +78,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let guards = parse_guards(data)?;
     let sleepiest_soldier = guards
@@ -94,16 +97,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
             .0;
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2018",
-    day: "4",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
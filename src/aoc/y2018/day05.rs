@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 
@@ -34,12 +35,16 @@ fn reactionate(mut line: String) -> usize {
     lastlen
 }
 
+#[solution(part = 1)]
+#[example(part = 1, with = crate::multi_line_example)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let line = data.next().unwrap();
     let ret = reactionate(line);
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
+#[example(part = 2, with = crate::multi_line_example)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let line = data.next().unwrap();
     let polymer_options = line.chars().map(|c| c.to_ascii_lowercase()).unique();
@@ -51,16 +56,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
         .unwrap();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2018",
-    day: "5",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::multi_line_example(data, part_1),
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| crate::multi_line_example(data, part_2),
-    }),
-});
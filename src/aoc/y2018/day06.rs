@@ -9,6 +9,7 @@
 
 use std::collections::HashSet;
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
 use crate::{Coord2D, Coordinate};
@@ -23,6 +24,7 @@ fn get_closest(coord: Coord2D, coords: &[Coord2D]) -> Option<Coord2D> {
     (a_dist != b_dist).then_some(*a)
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let coords: Vec<Coord2D> = data.map(|line| line.parse()).try_collect()?;
     let min = coords
@@ -71,6 +73,8 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2, params(max_distance = 10_000))]
+#[example(part = 2, params(max_distance = 32))]
 pub fn part_2(data: crate::DataIn, max_distance: u64) -> crate::AoCResult<String> {
     let coords: Vec<Coord2D> = data.map(|line| line.parse()).try_collect()?;
     let min = coords
@@ -97,16 +101,3 @@ pub fn part_2(data: crate::DataIn, max_distance: u64) -> crate::AoCResult<String
         .count();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2018",
-    day: "6",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: |data| part_2(data, 10_000),
-        example: |data| part_2(data, 32),
-    }),
-});
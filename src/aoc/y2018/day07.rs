@@ -9,6 +9,7 @@
 
 use std::collections::{HashMap, HashSet};
 
+use aoc_macros::{example, solution};
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -47,6 +48,7 @@ fn alphasort((id, _): &(&char, &Step)) -> impl Ord + use<> {
     u32::from('Z') - u32::from(**id)
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let steps = parse_steps(data)?;
     let mut done = HashSet::with_capacity(steps.len());
@@ -124,6 +126,8 @@ impl WorkerPool {
     }
 }
 
+#[solution(part = 2, params(num_helpers = 5, time_penalty = 60))]
+#[example(part = 2, params(num_helpers = 2, time_penalty = 0))]
 pub fn part_2(data: crate::DataIn, num_helpers: usize, time_penalty: u32) -> AoCResult<String> {
     let steps = parse_steps(data)?;
     let num_steps = steps.len();
@@ -170,16 +174,3 @@ pub fn part_2(data: crate::DataIn, num_helpers: usize, time_penalty: u32) -> AoC
 
     Ok(time.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2018",
-    day: "7",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: |data| part_2(data, 5, 60),
-        example: |data| part_2(data, 2, 0),
-    })
-});
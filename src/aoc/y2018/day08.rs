@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, AoCResult};
@@ -64,6 +65,7 @@ impl Node {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let numbers: Vec<u32> = data
         .next()
@@ -76,6 +78,7 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let numbers: Vec<u32> = data
         .next()
@@ -87,16 +90,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let ret = root_node.part_2_sum();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2018",
-    day: "8",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
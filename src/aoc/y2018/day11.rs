@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
 use crate::Coord2D;
@@ -20,6 +21,8 @@ fn power_level(coord: Coord2D, serial: i64) -> i32 {
     power_level - 5
 }
 
+#[solution(part = 1)]
+#[example(part = 1, with = crate::multi_line_example)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let serial = data.next().unwrap().parse()?;
 
@@ -43,16 +46,6 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
-inventory::submit!(crate::AoCDay {
-    year: "2018",
-    day: "11",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::multi_line_example(data, part_1),
-    },
-    part_2: None
-});
-
 #[cfg(test)]
 mod test {
     use crate::Coord2D;
@@ -7,6 +7,9 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
+
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret: u64 = 0;
     for line in data {
@@ -18,6 +21,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret: u64 = 0;
     for line in data {
@@ -36,16 +40,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "1",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
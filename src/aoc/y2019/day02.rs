@@ -7,10 +7,12 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use super::computer::Computer;
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     for line in data {
         let mut computer: Computer = line.parse().unwrap();
@@ -22,6 +24,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok("".to_string())
 }
 
+#[solution(part = 2, no_example)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     for line in data {
         let og_computer: Computer = line.parse().unwrap();
@@ -39,16 +42,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok("".to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "2",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: crate::no_example
-    }),
-});
@@ -9,6 +9,7 @@
 
 use std::{fmt::Display, str::FromStr};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, AoCResult, CommonGrid, Coord2D, Coordinate, Direction, InfGrid};
@@ -80,6 +81,7 @@ fn wire_it_up(
     Ok(())
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret = 0;
     while let Some(first) = data.next() {
@@ -133,6 +135,7 @@ fn steppinator(grid: &InfGrid<GridState>, first: &str, second: &str) -> AoCResul
         .ok_or(AoCError::new("no crossovers?"))
 }
 
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret = 0;
     while let Some(first) = data.next() {
@@ -154,16 +157,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "3",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -9,6 +9,8 @@
 
 use std::cmp::Ordering;
 
+use aoc_macros::solution;
+
 fn check_num_part_1(num: u32) -> bool {
     let num = num.to_string();
     if num.len() != 6 {
@@ -28,6 +30,7 @@ fn check_num_part_1(num: u32) -> bool {
     doubles
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let line = data.next().unwrap();
     let (start, end) = line.split_once('-').unwrap();
@@ -71,6 +74,7 @@ fn check_num_part_2(num: u32) -> bool {
     doubles
 }
 
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let line = data.next().unwrap();
     let (start, end) = line.split_once('-').unwrap();
@@ -85,20 +89,6 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "4",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
-
 #[cfg(test)]
 mod test {
     use super::check_num_part_2 as check_num;
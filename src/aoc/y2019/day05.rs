@@ -7,17 +7,22 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::{example, solution};
+
 use super::computer;
 
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "5",
-    part_1: crate::AoCPart {
-        main: |data| computer::run_one_with_input(data, &[1]),
-        example: |data| crate::multi_line_example(data, computer::run_one)
-    },
-    part_2: Some(crate::AoCPart {
-        main: |data| computer::run_one_with_input(data, &[5]),
-        example: |data| crate::multi_line_example(data, computer::run_one)
-    }),
-});
+#[solution(part = 1)]
+pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
+    computer::run_one_with_input(data, &[1])
+}
+
+#[solution(part = 2)]
+pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
+    computer::run_one_with_input(data, &[5])
+}
+
+#[example(part = 1, with = crate::multi_line_example)]
+#[example(part = 2, with = crate::multi_line_example)]
+fn run_example(data: crate::DataIn) -> crate::AoCResult<String> {
+    computer::run_one(data)
+}
@@ -9,6 +9,8 @@
 
 use std::collections::{HashMap, VecDeque};
 
+use aoc_macros::solution;
+
 #[derive(Debug, Default)]
 struct OrbitItem {
     name: String,
@@ -44,6 +46,7 @@ fn get_path<'a>(orbit_map: &'a HashMap<String, OrbitItem>, mut obj: &'a str) ->
     ret
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut orbit_map: HashMap<String, OrbitItem> = HashMap::with_capacity({
         let hint = data.size_hint();
@@ -99,6 +102,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut orbit_map: HashMap<String, OrbitItem> = HashMap::with_capacity({
         let hint = data.size_hint();
@@ -145,16 +149,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok((youtree.len() + santree.len()).to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "6",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
 use crate::AoCResult;
@@ -34,6 +35,8 @@ pub fn part_1_example(mut data: crate::DataIn) -> AoCResult<String> {
     Ok(ret.to_string())
 }
 
+// example modified for part 2, no longer compatible
+#[solution(part = 1, no_example)]
 pub fn part_1(mut data: crate::DataIn) -> AoCResult<String> {
     let mut ret = 0;
     let og_computer: Computer = data.next().unwrap().parse().unwrap();
@@ -86,6 +89,7 @@ fn run_amps(program: Computer, phase_settings: &[i64]) -> AoCResult<i64> {
     }
 }
 
+#[example(part = 2)]
 pub fn part_2_example(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret = 0;
     while let Some(phase) = data.next() {
@@ -99,6 +103,7 @@ pub fn part_2_example(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret = 0;
     let og_computer: Computer = data.next().unwrap().parse().unwrap();
@@ -109,17 +114,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "7",
-    part_1: crate::AoCPart {
-        main: part_1,
-        // example modified for part 2, no longer compatible
-        example: crate::no_example
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2_example
-    }),
-});
@@ -7,11 +7,13 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 const WIDTH: usize = 25;
 const HEIGHT: usize = 6;
 
+#[solution(part = 1, no_example)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let line = data.next().unwrap();
     let mut best_num_zero = usize::MAX;
@@ -28,7 +30,8 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
-pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
+#[solution(part = 2, no_example)]
+pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<crate::Answer> {
     let line = data.next().unwrap();
     let mut base_layer: Option<Vec<char>> = None;
     for layer_iter in line.chars().rev().chunks(WIDTH * HEIGHT).into_iter() {
@@ -50,32 +53,18 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
             }
         }
     }
-    for (i, char) in base_layer.unwrap().into_iter().rev().enumerate() {
-        if i % WIDTH == 0 {
-            println!();
-        }
-        print!(
-            "{}",
-            match char {
-                '2' => '!',
-                '1' => '#',
-                _ => ' ',
-            }
-        );
-    }
-    println!();
-    Ok("".to_string())
+    let picture = base_layer
+        .unwrap()
+        .into_iter()
+        .rev()
+        .map(|char| match char {
+            '2' => '!',
+            '1' => '#',
+            _ => ' ',
+        })
+        .chunks(WIDTH)
+        .into_iter()
+        .map(|row| row.collect::<String>())
+        .collect();
+    Ok(picture)
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "8",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: crate::no_example
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: crate::no_example
-    }),
-});
@@ -9,6 +9,8 @@
 
 use std::fmt::Display;
 
+use aoc_macros::solution;
+
 use crate::{AoCResult, CommonGrid, Coord2D, Direction, InfGrid, RotateDirection};
 
 use super::computer::{Computer, RunState};
@@ -83,6 +85,7 @@ impl Robot {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut robot = Robot::new(data.next().unwrap()).unwrap();
     let mut hull: InfGrid<Colour> = InfGrid::new();
@@ -97,6 +100,7 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(hull.grid.len().to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut robot = Robot::new(data.next().unwrap()).unwrap();
     let mut hull: InfGrid<Colour> = InfGrid::new();
@@ -110,16 +114,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(hull.grid.len().to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "11",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -9,6 +9,7 @@
 
 use std::{cmp::Ordering, fmt::Display, str::FromStr};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -103,6 +104,7 @@ impl Display for Moon {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let moons: Vec<Moon> = data.map(|line| line.parse()).try_collect().unwrap();
 
@@ -128,13 +130,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok((io.energy() + europa.energy() + ganymede.energy() + callisto.energy()).to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "12",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
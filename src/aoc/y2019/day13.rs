@@ -12,6 +12,7 @@ use std::io::{Write, stdin, stdout};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use termion::cursor::HideCursor;
 use termion::event::Key;
@@ -59,6 +60,7 @@ impl Display for GameScreen {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut computer: Computer = data.next().unwrap().parse().unwrap();
     let res = computer.run().unwrap();
@@ -147,22 +149,10 @@ fn run(mut computer: Computer) -> i64 {
     segment
 }
 
+#[solution(part = 2, interactive, needs_terminal)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut computer: Computer = data.next().unwrap().parse().unwrap();
     computer.set(0, 2.into());
 
     Ok(run(computer).to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "13",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -9,6 +9,7 @@
 
 use std::{collections::HashSet, fmt::Display};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use super::computer::Computer;
@@ -51,6 +52,7 @@ impl Display for GridState {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut computer: Computer = data.next().unwrap().parse().unwrap();
     computer.run_to_completion().unwrap();
@@ -86,6 +88,7 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let base_computer: Computer = data.next().unwrap().parse().unwrap();
     let mut computer = base_computer.clone();
@@ -175,16 +178,3 @@ n
         }
     }
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "17",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
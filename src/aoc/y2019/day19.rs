@@ -12,12 +12,19 @@ use std::{
     fmt::Display,
 };
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
 use crate::Grid;
 
 use super::computer::Computer;
 
+#[example(part = 1, broken = "Fails against the example input")]
+fn part_1_example(data: crate::DataIn) -> crate::AoCResult<String> {
+    part_1(data)
+}
+
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let base_computer: Computer = data.next().unwrap().parse().unwrap();
 
@@ -62,6 +69,7 @@ fn mode(numbers: &[f64]) -> Option<f64> {
     })
 }
 
+#[solution(part = 2, broken = "Unfinished, only prints out its workings")]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let base_computer: Computer = data.next().unwrap().parse().unwrap();
 
@@ -149,16 +157,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok("".to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "19",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
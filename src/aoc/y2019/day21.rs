@@ -7,6 +7,8 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
+
 use super::computer::Computer;
 
 const SPRINGCODE_PART_1: &str = "
@@ -37,9 +39,11 @@ AND T J
 RUN
 ";
 
-pub fn run_droid(mut data: crate::DataIn, input: &'static str) -> crate::AoCResult<String> {
+#[solution(part = 1, params(springcode = SPRINGCODE_PART_1), no_example)]
+#[solution(part = 2, params(springcode = SPRINGCODE_PART_2), no_example)]
+pub fn run_droid(mut data: crate::DataIn, springcode: &'static str) -> crate::AoCResult<String> {
     let mut computer: Computer = data.next().unwrap().parse().unwrap();
-    computer.add_ascii_input(input.trim_start());
+    computer.add_ascii_input(springcode.trim_start());
     computer.run_to_completion().unwrap();
     match computer.get_ascii_output() {
         Some(death) => Ok(death),
@@ -49,16 +53,3 @@ pub fn run_droid(mut data: crate::DataIn, input: &'static str) -> crate::AoCResu
         }
     }
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "21",
-    part_1: crate::AoCPart {
-        main: |data| run_droid(data, SPRINGCODE_PART_1),
-        example: crate::no_example
-    },
-    part_2: Some(crate::AoCPart {
-        main: |data| run_droid(data, SPRINGCODE_PART_2),
-        example: crate::no_example
-    }),
-});
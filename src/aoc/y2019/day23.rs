@@ -7,12 +7,14 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::aoc::y2019::computer::{Computer, RunState};
 
 const NUM_MACHINES: usize = 50;
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let base_code: Computer = data.next().unwrap().parse().unwrap();
 
@@ -53,6 +55,7 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     }
 }
 
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let base_code: Computer = data.next().unwrap().parse().unwrap();
 
@@ -114,16 +117,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
         }
     }
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "23",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
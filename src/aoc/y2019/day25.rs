@@ -10,10 +10,12 @@
 use std::fs::File;
 use std::io::prelude::*;
 
+use aoc_macros::solution;
 use text_io::read;
 
 use super::computer::{Computer, RunState};
 
+#[solution(part = 1, no_example, interactive)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut computer: Computer = data.next().unwrap().parse().unwrap();
     while let RunState::NeedsInput = computer.run().unwrap() {
@@ -45,13 +47,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(computer.get_ascii_output().unwrap())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2019",
-    day: "25",
-    part_1: crate::AoCPart {
-        main: part_2,
-        example: crate::no_example,
-    },
-    part_2: None,
-});
@@ -9,10 +9,12 @@
 
 use std::collections::HashSet;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::AoCError;
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let nums: Vec<u64> = data.map(|line| line.parse()).try_collect()?;
     let num_set: HashSet<_> = nums.iter().copied().collect();
@@ -31,6 +33,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let nums: Vec<u64> = data.map(|line| line.parse()).try_collect()?;
     let num_set: HashSet<_> = nums.iter().copied().collect();
@@ -54,16 +57,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .ok_or(AoCError::new("No matching numbers found"))?;
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2020",
-    day: "1",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
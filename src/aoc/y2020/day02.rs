@@ -8,6 +8,7 @@
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 use std::str::FromStr;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -53,27 +54,16 @@ impl Policy {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let policies: Vec<Policy> = data.map(|line| line.parse()).try_collect()?;
     let ret = policies.into_iter().filter(|p| p.part_1()).count();
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let policies: Vec<Policy> = data.map(|line| line.parse()).try_collect()?;
     let ret = policies.into_iter().filter(|p| p.part_2()).count();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2020",
-    day: "2",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
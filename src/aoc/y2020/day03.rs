@@ -10,6 +10,7 @@
 use std::fmt::Display;
 
 use aoc_macros::VoidState;
+use aoc_macros::solution;
 
 use crate::{CharGrid, CommonGrid, Coord2D, SparseGrid, symbols};
 
@@ -60,6 +61,7 @@ fn toboggonate(grid: &SparseGrid<GridState>, dir: Coord2D) -> usize {
     trees
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let grid: SparseGrid<GridState> = SparseGrid::new_from_chars(data)?;
     log::debug!("\n{grid:#}");
@@ -67,6 +69,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let grid: SparseGrid<GridState> = SparseGrid::new_from_chars(data)?;
     log::debug!("\n{grid:#}");
@@ -83,16 +86,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2020",
-    day: "3",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
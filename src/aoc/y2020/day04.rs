@@ -9,6 +9,7 @@
 
 use std::collections::HashSet;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 
@@ -45,6 +46,7 @@ fn parse_pports(data: crate::DataIn) -> crate::AoCResult<Vec<Vec<(String, String
     .try_collect()
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let passports = parse_pports(data)?;
     let ret = passports
@@ -62,6 +64,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let passports = parse_pports(data)?;
     let ret = passports
@@ -108,16 +111,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2020",
-    day: "4",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
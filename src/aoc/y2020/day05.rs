@@ -10,6 +10,7 @@
 use std::collections::HashSet;
 use std::str::FromStr;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use num::Integer;
 
@@ -70,6 +71,7 @@ impl Pass {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let passes: Vec<Pass> = data.map(|line| line.parse()).try_collect()?;
 
@@ -81,6 +83,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let passes: HashSet<u32> = data
         .map(|line| line.parse())
@@ -95,16 +98,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .ok_or(AoCError::new("No missing boarding pass!"))?;
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2020",
-    day: "5",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
@@ -9,8 +9,10 @@
 
 use std::collections::{HashMap, HashSet};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let ret: usize = data
         .batching(|iter| {
@@ -29,6 +31,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let ret: usize = data
         .batching(|iter| {
@@ -53,16 +56,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .sum();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2020",
-    day: "6",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
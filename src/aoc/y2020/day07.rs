@@ -10,6 +10,7 @@
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -86,6 +87,8 @@ fn baggo(data: crate::DataIn) -> crate::AoCResult<HashMap<BagID, Bag>> {
     Ok(bags)
 }
 
+#[solution(part = 1)]
+#[example(part = 1, with = crate::partitioned_example)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let bags = baggo(data)?;
 
@@ -108,6 +111,8 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
+#[example(part = 2, with = crate::partitioned_example)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let bags = baggo(data)?;
 
@@ -125,15 +130,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-inventory::submit!(crate::AoCDay {
-    year: "2020",
-    day: "7",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::partitioned_example(data, part_1)
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| crate::partitioned_example(data, part_2)
-    })
-});
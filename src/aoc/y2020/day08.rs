@@ -9,6 +9,7 @@
 
 use std::{collections::HashSet, str::FromStr};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::AoCError;
@@ -87,6 +88,7 @@ fn computate(opcodes: &[Opcode]) -> (Outcome, i32) {
     (Outcome::Terminated, acc)
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let opcodes: Vec<Opcode> = data.map(|line| line.parse()).try_collect()?;
     let (outcome, acc) = computate(&opcodes);
@@ -95,6 +97,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let opcodes: Vec<Opcode> = data.map(|line| line.parse()).try_collect()?;
 
@@ -112,16 +115,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2020",
-    day: "8",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
@@ -10,6 +10,7 @@
 use std::cmp::Ordering;
 use std::ops::Not;
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
 use crate::AoCError;
@@ -31,12 +32,16 @@ fn locate_fault(numbers: &[u64], window: usize) -> crate::AoCResult<u64> {
         .ok_or(AoCError::new("Couldn't find the dodgy number!"))
 }
 
+#[solution(part = 1, params(window = 25))]
+#[example(part = 1, params(window = 5))]
 pub fn part_1(data: crate::DataIn, window: usize) -> crate::AoCResult<String> {
     let numbers: Vec<_> = data.map(|line| line.parse()).try_collect()?;
     let ret = locate_fault(&numbers, window)?;
     Ok(ret.to_string())
 }
 
+#[solution(part = 2, params(window = 25))]
+#[example(part = 2, params(window = 5))]
 pub fn part_2(data: crate::DataIn, window: usize) -> crate::AoCResult<String> {
     let numbers: Vec<_> = data.map(|line| line.parse()).try_collect()?;
     let oddo = locate_fault(&numbers, window)?;
@@ -63,16 +68,3 @@ pub fn part_2(data: crate::DataIn, window: usize) -> crate::AoCResult<String> {
         .ok_or(AoCError::new("Couldn't find matching range :("))?;
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2020",
-    day: "9",
-    part_1: crate::AoCPart {
-        main: |data| part_1(data, 25),
-        example: |data| part_1(data, 5)
-    },
-    part_2: Some(crate::AoCPart {
-        main: |data| part_2(data, 25),
-        example: |data| part_2(data, 5)
-    }),
-});
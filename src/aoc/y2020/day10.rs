@@ -9,9 +9,13 @@
 
 use std::collections::HashMap;
 
-use cached::{UnboundCache, cached_key};
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
+use crate::Memo;
+
+#[solution(part = 1)]
+#[example(part = 1, with = crate::partitioned_example)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut adaptors: Vec<u64> = data.map(|line| line.parse()).try_collect()?;
     adaptors.push(0);
@@ -32,24 +36,23 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
-cached_key! {
-    PERMUTATE: UnboundCache<(u64, usize), u64> = UnboundCache::with_capacity(100);
-    // Include the length for the examples
-    Key = { (jolts, compatible.len()) };
-    fn permutate(jolts: u64, compatible: &HashMap<u64, Vec<u64>>) -> u64 = {
+fn permutate(memo: &mut Memo<u64, u64>, jolts: u64, compatible: &HashMap<u64, Vec<u64>>) -> u64 {
+    memo.get_or_insert_with(jolts, |memo| {
         let my_compat = &compatible[&jolts];
         log::debug!("Hello I'm {jolts} and my friends are {my_compat:?}");
         if my_compat.is_empty() {
-            return 1;
+            1
         } else {
             my_compat
                 .iter()
-                .map(|jolts| permutate(*jolts, compatible))
+                .map(|jolts| permutate(memo, *jolts, compatible))
                 .sum()
         }
-    }
+    })
 }
 
+#[solution(part = 2)]
+#[example(part = 2, with = crate::partitioned_example)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut adaptors: Vec<u64> = data.map(|line| line.parse()).try_collect()?;
     adaptors.push(0);
@@ -74,19 +77,6 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     log::debug!("compo {compatible:?} ");
 
-    let ret = permutate(0, &compatible);
+    let ret = permutate(&mut Memo::with_capacity(100), 0, &compatible);
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2020",
-    day: "10",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::partitioned_example(data, part_1)
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| crate::partitioned_example(data, part_2)
-    })
-});
@@ -9,6 +9,7 @@
 
 use std::fmt::Display;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, AoCResult, CharGrid, CommonGrid, Coord2D, Coordinate2D, InfGrid};
@@ -113,23 +114,12 @@ pub fn main(mut data: crate::DataIn, iterations: usize) -> crate::AoCResult<Stri
     Ok(ret.to_string())
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     main(data, 2)
 }
 
+#[solution(part = 2, slow)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     main(data, 50)
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2021",
-    day: "20",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -77,6 +78,7 @@ impl RebootStep {
     }
 }
 
+#[solution(part = 1, slow)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut grid: InfGrid<CubeState, Coord3D> = InfGrid::new();
 
@@ -103,13 +105,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
         .count();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2021",
-    day: "22",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
@@ -9,6 +9,7 @@
 
 #![allow(dead_code, unused_variables, unused_mut)]
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
 use crate::AoCError;
@@ -170,6 +171,7 @@ impl Computer {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let instructions: Vec<Instruction> = data.map(|line| line.as_str().try_into()).try_collect()?;
 
@@ -194,6 +196,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Err(AoCError::new("Nothing computed??"))
 }
 
+#[example(part = 1, with = crate::partitioned_example)]
 pub fn part_1_example(data: crate::DataIn) -> crate::AoCResult<String> {
     let instructions: Vec<Instruction> = data.map(|line| line.as_str().try_into()).try_collect()?;
     let mut computer = Computer::new();
@@ -205,13 +208,3 @@ pub fn part_1_example(data: crate::DataIn) -> crate::AoCResult<String> {
         computer.w, computer.x, computer.y, computer.z
     ))
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2021",
-    day: "24",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::partitioned_example(data, part_1_example)
-    },
-    part_2: None,
-});
@@ -7,6 +7,8 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
+
 fn run_elves(data: crate::DataIn) -> Vec<i32> {
     let mut elves = vec![];
     let mut running_elf = 0;
@@ -28,25 +30,14 @@ fn run_elves(data: crate::DataIn) -> Vec<i32> {
     elves
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let elves = run_elves(data);
     Ok(elves[0].to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let elves = run_elves(data);
     Ok(elves[..3].iter().sum::<i32>().to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "1",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
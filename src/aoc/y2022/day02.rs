@@ -7,6 +7,8 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
+
 #[derive(Debug)]
 enum Result {
     Win = 6,
@@ -64,6 +66,7 @@ fn game_result_part_1(you: &Move, elf: &Move) -> Result {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut total_score: u32 = 0;
 
@@ -106,6 +109,7 @@ fn game_result_part_2(you: &Result, elf: &Move) -> Move {
     }
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut total_score: u32 = 0;
 
@@ -126,16 +130,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(total_score.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "2",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use std::collections::HashSet;
 
@@ -23,6 +24,7 @@ fn get_priority(item: char) -> u32 {
     item as u32 - ('a' as u32 - 1)
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut total_prio = 0;
     for line in data {
@@ -62,6 +64,7 @@ fn get_comomn_item<L: Iterator<Item = String>>(lines: L) -> Option<char> {
     Some(*intersection[0])
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut total_prio = 0;
     for lines in &data.chunks(3) {
@@ -75,16 +78,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(total_prio.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "3",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
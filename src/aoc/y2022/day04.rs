@@ -7,6 +7,8 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
+
 struct Range {
     start: u32,
     end: u32,
@@ -30,6 +32,7 @@ impl Range {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut overlap = 0;
     for pair in data {
@@ -43,6 +46,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(overlap.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut overlap = 0;
     for pair in data {
@@ -55,16 +59,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(overlap.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "4",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
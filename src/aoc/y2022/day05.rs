@@ -9,6 +9,7 @@
 
 use std::{collections::HashMap, fmt::Display};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -144,6 +145,7 @@ impl Move {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut yard = Yard::new(&mut data);
     println!("{}", yard);
@@ -155,6 +157,7 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(yard.get_top())
 }
 
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut yard = Yard::new(&mut data);
     println!("{}", yard);
@@ -165,16 +168,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(yard.get_top())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "5",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -6,8 +6,13 @@
 // You should have received a copy of the Licence along with this work. If not, see:
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
+#[solution(part = 1, params(window_size = 4))]
+#[example(part = 1, params(window_size = 4), with = crate::multi_line_example)]
+#[solution(part = 2, params(window_size = 14))]
+#[example(part = 2, params(window_size = 14), with = crate::multi_line_example)]
 fn main(mut data: crate::DataIn, window_size: usize) -> crate::AoCResult<String> {
     let chars = data.next().unwrap().chars().collect_vec();
     for i in 0.. {
@@ -18,16 +23,3 @@ fn main(mut data: crate::DataIn, window_size: usize) -> crate::AoCResult<String>
     }
     unreachable!()
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "6",
-    part_1: crate::AoCPart {
-        main: |data| main(data, 4),
-        example: |data| crate::multi_line_example(data, |data| main(data, 4))
-    },
-    part_2: Some(crate::AoCPart {
-        main: |data| main(data, 14),
-        example: |data| crate::multi_line_example(data, |data| main(data, 14))
-    }),
-});
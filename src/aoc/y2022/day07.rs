@@ -12,6 +12,8 @@ use std::cell::RefCell;
 use std::fmt::Display;
 use std::rc::{Rc, Weak};
 
+use aoc_macros::solution;
+
 type INoder = RefCell<INode>;
 
 enum INode {
@@ -194,6 +196,7 @@ fn folderize(data: crate::DataIn) -> (Rc<INoder>, Vec<Rc<INoder>>) {
     (root, folders)
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let (_, folders) = folderize(data);
 
@@ -211,6 +214,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(size.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let (root, folders) = folderize(data);
 
@@ -236,16 +240,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(size.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "7",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
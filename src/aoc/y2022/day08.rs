@@ -11,6 +11,7 @@ use std::collections::HashMap;
 use std::fmt::Display;
 
 use ansi_term::{Color, Style};
+use aoc_macros::solution;
 use lazy_static::lazy_static;
 
 use crate::Direction;
@@ -219,6 +220,7 @@ impl ForestPart1 {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut data = data.peekable();
     let size = data.peek().unwrap().len();
@@ -367,6 +369,7 @@ impl Display for ForestPart2 {
     }
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut data = data.peekable();
     let size = data.peek().unwrap().len();
@@ -376,16 +379,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     // println!("{}", forest);
     Ok(forest.get_visibilist().to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "8",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -10,6 +10,8 @@
 use std::collections::HashSet;
 use std::str::FromStr;
 
+use aoc_macros::solution;
+
 use crate::{AoCError, Coord2D, Direction};
 
 struct Instruction {
@@ -62,6 +64,7 @@ impl Coord2D {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut head_pos = Coord2D { x: 0, y: 0 };
     let mut tail_pos = head_pos;
@@ -93,6 +96,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(visited.len().to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut head_pos = Coord2D { x: 0, y: 0 };
     let mut midroll: [Coord2D; 8] = [head_pos; 8];
@@ -135,16 +139,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(visited.len().to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "9",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -9,6 +9,8 @@
 
 use std::fmt::Display;
 
+use aoc_macros::solution;
+
 const MAGIC_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
 
 enum Instruction {
@@ -77,16 +79,16 @@ impl Cpu {
         }
     }
 
-    fn cycle_part_2(&mut self) {
+    fn cycle_part_2(&mut self, screen: &mut String) {
         let sprpos = self.x;
         let px = (self.pc % 40) as i64;
-        if px == 0 {
-            println!();
+        if px == 0 && self.pc > 0 {
+            screen.push('\n');
         }
         if px >= sprpos - 1 && px <= sprpos + 1 {
-            print!("#");
+            screen.push('#');
         } else {
-            print!(" ");
+            screen.push(' ');
         }
 
         let instruction = &self.instructions[self.pc];
@@ -107,6 +109,7 @@ impl Cpu {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut cpu = Cpu::new(&mut data);
     println!(" {:>3} | {:^4} | {:^8} | {:4}", "pc", "x", "inst", "newx");
@@ -121,25 +124,12 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(signals.to_string())
 }
 
-pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
+#[solution(part = 2)]
+pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<crate::Answer> {
     let mut cpu = Cpu::new(&mut data);
-    println!(" {:>3} | {:^4} | {:^8} | {:4}", "pc", "x", "inst", "newx");
+    let mut screen = String::new();
     while !cpu.done_part_2() {
-        cpu.cycle_part_2();
+        cpu.cycle_part_2(&mut screen);
     }
-    println!();
-    Ok(String::new())
+    Ok(crate::Answer::Grid(screen))
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "10",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
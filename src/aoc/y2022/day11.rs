@@ -9,6 +9,7 @@
 
 use std::fmt::Display;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 type MonkeyID = usize;
@@ -202,6 +203,7 @@ impl Display for Monke {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut data = data.peekable();
     let mut monkeys = Vec::with_capacity(8);
@@ -250,13 +252,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(res.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "11",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
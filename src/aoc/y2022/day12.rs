@@ -9,6 +9,7 @@
 
 use crate::{CommonGrid, Coord2D, Coordinate, FlatGrid};
 use ansi_term::{Color, Style};
+use aoc_macros::solution;
 use lazy_static::lazy_static;
 use std::cmp;
 use std::collections::BinaryHeap;
@@ -261,6 +262,7 @@ impl Display for Grid {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut grid = Grid::new_part_1(data);
 
@@ -299,6 +301,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     panic!("no path to exit");
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut grid = Grid::new_part_2(data);
 
@@ -335,16 +338,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     panic!("no path to exit");
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "12",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
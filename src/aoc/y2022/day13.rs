@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::EitherOrBoth as EoB;
 use itertools::Itertools;
 use std::cmp::Ordering;
@@ -70,6 +71,7 @@ fn check_order(left: Value, right: Value) -> Order {
     actual_check_order(&left, &right).unwrap()
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut index = 0;
     let mut ret = 0;
@@ -101,6 +103,7 @@ impl Display for Sigh<'_> {
     }
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut signal = Vec::with_capacity(data.size_hint().0 + 2);
     let first_marker = json!([[2]]);
@@ -135,16 +138,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         + 1;
     Ok((fpos * spos).to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "13",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
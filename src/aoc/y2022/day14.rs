@@ -10,6 +10,7 @@
 use std::cmp;
 use std::fmt::Display;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, CommonGrid, Coord2D, Coordinate, Direction, Grid, InfGrid};
@@ -75,6 +76,7 @@ fn settle_sand_part_1(sand_start: Coord2D, grid: &Grid<GridData>) -> Option<Coor
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut max = Coord2D {
         x: i32::MIN,
@@ -173,6 +175,7 @@ fn settle_sand_part_2(
     }
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut maxy = 0;
     let rocks: Vec<Vec<Coord2D>> = data
@@ -224,16 +227,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(i.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "14",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -9,6 +9,7 @@
 
 use std::cmp;
 
+use aoc_macros::solution;
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -46,6 +47,7 @@ impl Reading {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let readings: Vec<Reading> = data.map(Reading::parse).collect();
     let (min, max, maxdist) = readings.iter().fold(
@@ -72,13 +74,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(count.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "15",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
@@ -12,6 +12,7 @@ use std::{
     fmt::Display,
 };
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -159,6 +160,12 @@ impl Display for Volcano {
     }
 }
 
+#[example(part = 1, broken = "Gets the wrong answer for the example")]
+fn part_1_example(data: crate::DataIn) -> crate::AoCResult<String> {
+    part_1(data)
+}
+
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut volcano = Volcano::new(data);
     let mut current_valve = "AA".to_owned();
@@ -182,13 +189,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(volcano.pressure_released.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "16",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
@@ -9,6 +9,7 @@
 
 use std::fmt::Display;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, AoCResult, CommonGrid, Coord2D, Direction, InfGrid};
@@ -161,6 +162,7 @@ fn drop_shape(
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> AoCResult<String> {
     let instructions: Vec<Direction> = data
         .next()
@@ -190,13 +192,3 @@ pub fn part_1(mut data: crate::DataIn) -> AoCResult<String> {
 
     Ok(floor.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2022",
-    day: "17",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 const NUMBERS: [&str; 18] = [
@@ -42,6 +43,7 @@ fn find_first(line: &str, nums: &[&str]) -> usize {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut total = 0;
     for line in data {
@@ -61,6 +63,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(total.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut total = 0;
     for line in data {
@@ -74,16 +77,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(total.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "1",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
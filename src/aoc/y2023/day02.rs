@@ -9,6 +9,7 @@
 
 use std::{fmt::Display, str::FromStr};
 
+use aoc_macros::solution;
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -129,6 +130,7 @@ impl Game {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret = 0;
     let state = State {
@@ -147,6 +149,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret = 0;
     for line in data {
@@ -156,16 +159,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "2",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
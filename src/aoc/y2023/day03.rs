@@ -10,6 +10,7 @@
 use std::collections::{HashSet, VecDeque};
 use std::fmt::Display;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{CommonGrid, Coord2D, FlatGrid, Grid};
@@ -44,6 +45,7 @@ impl Display for GridState {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut data = data.peekable();
     let width = data.peek().unwrap().len().try_into().unwrap();
@@ -109,6 +111,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut data = data.peekable();
     let width = data.peek().unwrap().len().try_into().unwrap();
@@ -178,16 +181,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "3",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
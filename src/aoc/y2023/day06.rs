@@ -7,8 +7,10 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let times = data.next().unwrap();
     let mut times = times.split_whitespace();
@@ -42,6 +44,7 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
 }
 
 #[allow(unused_variables, unused_mut)]
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let times = data.next().unwrap();
     let mut times = times.split_whitespace();
@@ -67,16 +70,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "6",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
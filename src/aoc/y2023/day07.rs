@@ -11,6 +11,7 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::AoCError;
@@ -169,6 +170,7 @@ impl FromStr for Hand {
     }
 }
 
+#[solution(part = 1)]
 fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret = 0;
     let mut hands: Vec<Hand> = data.map(|line| line.parse()).try_collect().unwrap();
@@ -180,20 +182,8 @@ fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let data: crate::AoCData = data.map(|line| line.replace('J', "*")).collect();
     part_1(data.into_iter())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "7",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
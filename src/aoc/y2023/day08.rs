@@ -9,12 +9,15 @@
 
 use std::collections::HashMap;
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 
 use crate::Direction;
 
+// example modified for part 2, no longer compatible
+#[solution(part = 1, no_example)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut instructions = data
         .next()
@@ -82,6 +85,15 @@ fn navigate<'a>(
     }
 }
 
+#[example(
+    part = 2,
+    broken = "Relies on the real input's loops lining up with the instructions"
+)]
+fn part_2_example(data: crate::DataIn) -> crate::AoCResult<String> {
+    part_2(data)
+}
+
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let raw_instructions = data
         .next()
@@ -135,17 +147,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "8",
-    part_1: crate::AoCPart {
-        main: part_1,
-        // example modified for part 2, no longer compatible
-        example: crate::no_example
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
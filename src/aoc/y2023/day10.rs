@@ -10,9 +10,11 @@
 use std::collections::{HashSet, VecDeque};
 use std::fmt::Display;
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
-use crate::{AoCError, CommonGrid, Coord2D, Direction, FlatGrid, Grid};
+use crate::utils::regions::flood_fill;
+use crate::{AoCError, CommonGrid, Connectivity, Coord2D, Direction, FlatGrid, Grid};
 
 #[derive(Debug)]
 enum PipeSegment {
@@ -96,6 +98,8 @@ impl PipeSegment {
     }
 }
 
+#[solution(part = 1)]
+#[example(part = 1, with = crate::partitioned_example)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let grid: Grid<PipeSegment> = Grid::new_from_lines(data.map(|line| {
         line.chars()
@@ -140,6 +144,8 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
+#[example(part = 2, with = crate::partitioned_example)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut grid: Grid<PipeSegment> = Grid::new_from_lines(data.map(|line| {
         line.chars()
@@ -225,26 +231,14 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     // Conveniently it's always safe to start in the top left because on my input data
     // the top left is never the starting position
 
-    // Fill seen with the pipes so we'll never iterate into them and so I won't have to bother about filtering
-    let mut seen: HashSet<_> = grid
-        .iter()
-        .filter(|(_, item)| matches!(item, Pipe2::Pipe))
-        .map(|(coord, _)| coord)
-        .copied()
-        .collect();
-
-    let mut queue = VecDeque::new();
-    queue.push_back((0, 0).into());
-    while let Some(coord) = queue.pop_front() {
-        if !seen.insert(coord) {
-            continue;
-        }
-
-        grid.set(coord, Pipe2::Outside);
-        queue.extend(
-            grid.get_neighbour_coords(coord, false)
-                .filter(|coord| !seen.contains(coord)),
-        );
+    let outside = flood_fill(
+        &grid,
+        [(0, 0).into()],
+        Connectivity::Orthogonal,
+        |_, item| !matches!(item, Pipe2::Pipe),
+    );
+    for coord in outside.iter() {
+        grid.set(*coord, Pipe2::Outside);
     }
 
     println!("{grid}");
@@ -262,16 +256,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "10",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| crate::partitioned_example(data, part_1)
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| crate::partitioned_example(data, part_2)
-    }),
-});
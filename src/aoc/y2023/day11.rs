@@ -9,6 +9,7 @@
 
 use std::{collections::HashSet, fmt::Display};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, Coordinate, InfGrid, utils::bigcoord2d::BigCoord2D};
@@ -91,23 +92,12 @@ fn main(data: crate::DataIn, expansion_factor: usize) -> crate::AoCResult<String
     Ok(ret.to_string())
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     main(data, 1)
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     main(data, 999_999)
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "11",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
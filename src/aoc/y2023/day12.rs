@@ -9,6 +9,7 @@
 
 use std::fmt::{Debug, Display};
 
+use aoc_macros::solution;
 use itertools::{EitherOrBoth, Itertools, repeat_n};
 
 use crate::AoCError;
@@ -72,6 +73,7 @@ fn check_valid(state1: &[SpringState], state2: &[u32]) -> bool {
         })
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret = 0;
     for line in data {
@@ -103,13 +105,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "12",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
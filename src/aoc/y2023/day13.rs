@@ -7,12 +7,40 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
-use std::cmp::Ordering;
-
+use aoc_macros::solution;
 use itertools::Itertools;
 
-use crate::{CommonGrid, FlatGrid, Grid};
+use crate::{CommonGrid, FlatGrid, Grid, GridViews};
+
+/// Whether every row reads the same both ways out from a mirror between
+/// columns `split - 1` and `split`
+fn mirrored_columns(grid: &Grid<char>, split: usize) -> bool {
+    let split = split as i32;
+    (0..grid.height as i32).all(|y| {
+        grid.row(y)
+            .take_while(|(coord, _)| coord.x < split)
+            .all(|(coord, c)| {
+                grid.get(&(2 * split - 1 - coord.x, y).into())
+                    .is_none_or(|other| other == c)
+            })
+    })
+}
+
+/// Whether every column reads the same both ways out from a mirror between
+/// rows `split - 1` and `split`
+fn mirrored_rows(grid: &Grid<char>, split: usize) -> bool {
+    let split = split as i32;
+    (0..grid.width as i32).all(|x| {
+        grid.column(x)
+            .take_while(|(coord, _)| coord.y < split)
+            .all(|(coord, c)| {
+                grid.get(&(x, 2 * split - 1 - coord.y).into())
+                    .is_none_or(|other| other == c)
+            })
+    })
+}
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut data = data.peekable();
     let mut ret = 0;
@@ -24,32 +52,9 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
         let grid: Grid<char> = Grid::new_from_lines(lines);
         println!("{grid}");
         // horizontal first
-        let splitend = grid.width as usize;
-        let mut potential_splits = (1..splitend).collect_vec();
-        for row in 0..grid.height {
-            let row = grid.get_row(row).into_iter().map(|(_, i)| *i).collect_vec();
-            let mut row_rev = row.clone();
-            row_rev.reverse();
-            for split in potential_splits.drain(..).collect_vec() {
-                let mut left = &row[..split];
-                let mut right = &row_rev[..splitend - split];
-                let llen = left.len();
-                let rlen = right.len();
-                match llen.cmp(&rlen) {
-                    Ordering::Greater => {
-                        left = &left[llen - rlen..];
-                    }
-                    Ordering::Less => {
-                        right = &right[rlen - llen..];
-                    }
-                    Ordering::Equal => (),
-                }
-                if left == right {
-                    potential_splits.push(split);
-                }
-                // println!("{} {left:?} {right:?} {}", split, left == right);
-            }
-        }
+        let potential_splits = (1..grid.width as usize)
+            .filter(|split| mirrored_columns(&grid, *split))
+            .collect_vec();
         match potential_splits.len() {
             1 => {
                 let splittr = potential_splits[0];
@@ -62,36 +67,9 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
                 println!("No vertical mirrors");
             }
         }
-        let splitend = grid.height as usize;
-        let mut potential_splits = (1..splitend).collect_vec();
-        for column in 0..grid.width {
-            let col = grid
-                .get_column(column)
-                .into_iter()
-                .map(|(_, i)| *i)
-                .collect_vec();
-            let mut col_rev = col.clone();
-            col_rev.reverse();
-            for split in potential_splits.drain(..).collect_vec() {
-                let mut left = &col[..split];
-                let mut right = &col_rev[..splitend - split];
-                let llen = left.len();
-                let rlen = right.len();
-                match llen.cmp(&rlen) {
-                    Ordering::Greater => {
-                        left = &left[llen - rlen..];
-                    }
-                    Ordering::Less => {
-                        right = &right[rlen - llen..];
-                    }
-                    Ordering::Equal => (),
-                }
-                if left == right {
-                    potential_splits.push(split);
-                }
-                // println!("{} {left:?} {right:?} {}", split, left == right);
-            }
-        }
+        let potential_splits = (1..grid.height as usize)
+            .filter(|split| mirrored_rows(&grid, *split))
+            .collect_vec();
         match potential_splits.len() {
             1 => {
                 let splittr = potential_splits[0];
@@ -114,77 +92,26 @@ enum MirrorRes {
 
 fn check_grid(grid: &Grid<char>, baseline: MirrorRes) -> MirrorRes {
     // horizontal first
-    let splitend = grid.width as usize;
-    let mut potential_splits = (1..splitend)
+    let potential_splits = (1..grid.width as usize)
         .filter(|l| MirrorRes::Vertcial(*l) != baseline)
+        .filter(|split| mirrored_columns(grid, *split))
         .collect_vec();
-    for row in 0..grid.height {
-        let row = grid.get_row(row).into_iter().map(|(_, i)| *i).collect_vec();
-        let mut row_rev = row.clone();
-        row_rev.reverse();
-        for split in potential_splits.drain(..).collect_vec() {
-            let mut left = &row[..split];
-            let mut right = &row_rev[..splitend - split];
-            let llen = left.len();
-            let rlen = right.len();
-            match llen.cmp(&rlen) {
-                Ordering::Greater => {
-                    left = &left[llen - rlen..];
-                }
-                Ordering::Less => {
-                    right = &right[rlen - llen..];
-                }
-                Ordering::Equal => (),
-            }
-            if left == right {
-                potential_splits.push(split);
-            }
-            // println!("{} {left:?} {right:?} {}", split, left == right);
-        }
-    }
     match potential_splits.len() {
         1 => return MirrorRes::Vertcial(potential_splits[0]),
         x if x > 1 => return MirrorRes::Void,
         _ => (),
     }
-    let splitend = grid.height as usize;
-    let mut potential_splits = (1..splitend)
+    let potential_splits = (1..grid.height as usize)
         .filter(|l| MirrorRes::Horizontal(*l) != baseline)
+        .filter(|split| mirrored_rows(grid, *split))
         .collect_vec();
-    for column in 0..grid.width {
-        let col = grid
-            .get_column(column)
-            .into_iter()
-            .map(|(_, i)| *i)
-            .collect_vec();
-        let mut col_rev = col.clone();
-        col_rev.reverse();
-        for split in potential_splits.drain(..).collect_vec() {
-            let mut left = &col[..split];
-            let mut right = &col_rev[..splitend - split];
-            let llen = left.len();
-            let rlen = right.len();
-            match llen.cmp(&rlen) {
-                Ordering::Greater => {
-                    left = &left[llen - rlen..];
-                }
-                Ordering::Less => {
-                    right = &right[rlen - llen..];
-                }
-                Ordering::Equal => (),
-            }
-            if left == right {
-                potential_splits.push(split);
-            }
-            // println!("{} {left:?} {right:?} {}", split, left == right);
-        }
-    }
     match potential_splits.len() {
         1 => MirrorRes::Horizontal(potential_splits[0]),
         _ => MirrorRes::Void,
     }
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut data = data.peekable();
     let mut ret = 0;
@@ -226,16 +153,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "13",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
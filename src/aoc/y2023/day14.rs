@@ -10,9 +10,10 @@
 use std::collections::HashSet;
 use std::fmt::Display;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
-use crate::{AoCError, CommonGrid, FlatGrid, Grid};
+use crate::{AoCError, CommonGrid, Coord2D, DenseGrid, Direction, FlatGrid, GridViews, Symmetry};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum GridState {
@@ -45,15 +46,18 @@ impl Display for GridState {
     }
 }
 
-fn slide_north(grid: &mut Grid<GridState>) {
-    for y in 1..grid.height {
-        for x in 0..grid.width {
-            let coord = (x, y).try_into().unwrap();
+/// Rolls every pebble as far north as it'll go. Use a view to tilt in any
+/// other direction.
+fn tilt(grid: &mut impl CommonGrid<Coord2D, GridState>) {
+    let max = grid.max_key();
+    for y in 1..=max.y {
+        for x in 0..=max.x {
+            let coord = (x, y).into();
             if let Some(GridState::Pebble) = grid.get(&coord) {
                 // long slow slide time
                 let mut last_good = None;
                 for y2 in (0..y).rev() {
-                    let coord2 = (x, y2).try_into().unwrap();
+                    let coord2 = (x, y2).into();
                     match grid.get(&coord2).unwrap() {
                         GridState::Air => last_good = Some(coord2),
                         _ => break,
@@ -68,104 +72,36 @@ fn slide_north(grid: &mut Grid<GridState>) {
     }
 }
 
-fn slide_east(grid: &mut Grid<GridState>) {
-    for x in (0..grid.width - 1).rev() {
-        for y in 0..grid.height {
-            let coord = (x, y).try_into().unwrap();
-            if let Some(GridState::Pebble) = grid.get(&coord) {
-                // long slow slide time
-                let mut last_good = None;
-                for x2 in x + 1..grid.width {
-                    let coord2 = (x2, y).try_into().unwrap();
-                    match grid.get(&coord2).unwrap() {
-                        GridState::Air => last_good = Some(coord2),
-                        _ => break,
-                    }
-                }
-                if let Some(last_good) = last_good {
-                    grid.set(coord, GridState::Air);
-                    grid.set(last_good, GridState::Pebble);
-                }
-            }
-        }
+fn spin_cycle(grid: &mut DenseGrid<GridState>) {
+    for direction in [
+        Direction::North,
+        Direction::West,
+        Direction::South,
+        Direction::East,
+    ] {
+        tilt(&mut grid.view_mut(Symmetry::facing(direction)));
     }
 }
 
-fn slide_south(grid: &mut Grid<GridState>) {
-    for y in (0..grid.height - 1).rev() {
-        for x in 0..grid.width {
-            let coord = (x, y).try_into().unwrap();
-            if let Some(GridState::Pebble) = grid.get(&coord) {
-                // long slow slide time
-                let mut last_good = None;
-                for y2 in y + 1..grid.height {
-                    let coord2 = (x, y2).try_into().unwrap();
-                    match grid.get(&coord2).unwrap() {
-                        GridState::Air => last_good = Some(coord2),
-                        _ => break,
-                    }
-                }
-                if let Some(last_good) = last_good {
-                    grid.set(coord, GridState::Air);
-                    grid.set(last_good, GridState::Pebble);
-                }
-            }
-        }
-    }
-}
-
-fn slide_west(grid: &mut Grid<GridState>) {
-    for x in 1..grid.width {
-        for y in 0..grid.height {
-            let coord = (x, y).try_into().unwrap();
-            if let Some(GridState::Pebble) = grid.get(&coord) {
-                // long slow slide time
-                let mut last_good = None;
-                for x2 in (0..x).rev() {
-                    let coord2 = (x2, y).try_into().unwrap();
-                    match grid.get(&coord2).unwrap() {
-                        GridState::Air => last_good = Some(coord2),
-                        _ => break,
-                    }
-                }
-                if let Some(last_good) = last_good {
-                    grid.set(coord, GridState::Air);
-                    grid.set(last_good, GridState::Pebble);
-                }
-            }
-        }
-    }
-}
-
-fn spin_cycle(grid: &mut Grid<GridState>) {
-    slide_north(grid);
-    slide_west(grid);
-    slide_south(grid);
-    slide_east(grid);
-}
-
-fn loading(grid: &Grid<GridState>) -> u32 {
+fn loading(grid: &DenseGrid<GridState>) -> u32 {
     grid.iter()
         .filter(|(_, value)| matches!(value, GridState::Pebble))
         .map(|(coord, _)| grid.height - coord.y as u32)
         .sum::<u32>()
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
-    let mut grid: Grid<GridState> = Grid::new_from_lines(
+    let mut grid: DenseGrid<GridState> = DenseGrid::new_from_lines(
         data.map(|line| line.chars().map(|c| c.try_into().unwrap()).collect_vec()),
     );
-    slide_north(&mut grid);
-    Ok(grid
-        .iter()
-        .filter(|(_, value)| matches!(value, GridState::Pebble))
-        .map(|(coord, _)| grid.height - coord.y as u32)
-        .sum::<u32>()
-        .to_string())
+    tilt(&mut grid);
+    Ok(loading(&grid).to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
-    let mut grid: Grid<GridState> = Grid::new_from_lines(
+    let mut grid: DenseGrid<GridState> = DenseGrid::new_from_lines(
         data.map(|line| line.chars().map(|c| c.try_into().unwrap()).collect_vec()),
     );
     println!("{grid}");
@@ -218,16 +154,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok("".to_owned())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "14",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -7,6 +7,9 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
+
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret = 0;
     for value in data.next().unwrap().split(',') {
@@ -37,6 +40,7 @@ fn find_target(lens: &str, target_box: &[(String, u32)]) -> Option<usize> {
         .map(|(i, _)| i)
 }
 
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut boxes: Vec<Vec<(String, u32)>> = (0..=255).map(|_| Vec::new()).collect();
     for value in data.next().unwrap().split(',') {
@@ -70,16 +74,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
         .sum::<usize>()
         .to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "15",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -12,6 +12,7 @@ use std::{
     fmt::Display,
 };
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, CommonGrid, Coord2D, Direction, FlatGrid, Grid};
@@ -102,6 +103,7 @@ fn energise(grid: &Grid<GridState>, first: LightStep) -> usize {
     seen.into_iter().map(|(pos, _)| pos).unique().count()
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let grid: Grid<GridState> = Grid::new_from_lines(
         data.map(|line| line.chars().map(|c| c.try_into().unwrap()).collect_vec()),
@@ -110,6 +112,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let grid: Grid<GridState> = Grid::new_from_lines(
         data.map(|line| line.chars().map(|c| c.try_into().unwrap()).collect_vec()),
@@ -142,16 +145,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .unwrap()
         .to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "16",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
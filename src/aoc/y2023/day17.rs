@@ -9,6 +9,7 @@
 
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::utils::astar;
@@ -121,23 +122,12 @@ fn main(data: crate::DataIn, min_steppe: u32, max_steppe: u32) -> crate::AoCResu
         .to_string())
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     main(data, 0, 3)
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     main(data, 4, 10)
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "17",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -10,6 +10,7 @@
 use std::fmt::Display;
 use std::str::FromStr;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -87,6 +88,7 @@ impl Display for GridState {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let instructions: Vec<InstructionPart1> = data.map(|line| line.parse()).try_collect()?;
     let mut grid: InfGrid<GridState> = InfGrid::new();
@@ -158,6 +160,7 @@ impl FromStr for InstructionPart2 {
     }
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let instructions: Vec<InstructionPart2> = data.map(|line| line.parse()).try_collect()?;
     let mut pos: BigCoord2D = Default::default();
@@ -187,16 +190,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok((interior as usize + (exterior / 2) + 1).to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "18",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -9,6 +9,7 @@
 
 use std::{collections::HashMap, str::FromStr};
 
+use aoc_macros::{AocParse, solution};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -181,7 +182,8 @@ impl FromStr for Workflow {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, AocParse)]
+#[aoc(pattern = "{{x={x},m={m},a={a},s={s}}}")]
 struct Part {
     x: u32,
     m: u32,
@@ -195,26 +197,7 @@ impl Part {
     }
 }
 
-impl FromStr for Part {
-    type Err = AoCError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}$").unwrap();
-        }
-        let matches = RE
-            .captures(s)
-            .ok_or_else(|| AoCError::new(format!("Line {s} does not match regex!")))?;
-
-        Ok(Self {
-            x: matches[1].parse()?,
-            m: matches[2].parse()?,
-            a: matches[3].parse()?,
-            s: matches[4].parse()?,
-        })
-    }
-}
-
+#[solution(part = 1)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let workflows: HashMap<String, Workflow> = data
         .by_ref()
@@ -245,13 +228,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "19",
-    part_1: crate::AoCPart {
-        main: part_2,
-        example: part_2
-    },
-    part_2: None,
-});
@@ -13,6 +13,7 @@ use std::{
     str::FromStr,
 };
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -169,6 +170,7 @@ fn push_the_button(modules: &mut HashMap<String, Module>) -> (u64, u64) {
     (lows, highs)
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut modules: HashMap<String, Module> = data
         .map(|line| line.parse())
@@ -211,13 +213,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
         .unwrap();
     Ok((lows * highs).to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "20",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
@@ -7,9 +7,12 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
-use std::{collections::HashSet, fmt::Display};
+use std::fmt::Display;
 
-use crate::{AoCError, CharGrid, CommonGrid, Coord2D, Grid};
+use aoc_macros::{example, solution};
+
+use crate::utils::pathfinding::reachable_within_unbounded;
+use crate::{AoCError, BigCoord2D, CharGrid, CommonGrid, Connectivity, Grid, TiledGrid};
 
 #[allow(dead_code)]
 enum GridState {
@@ -44,54 +47,103 @@ impl Display for GridState {
     }
 }
 
-fn big_steppe(grid: &Grid<GridState>, steps: HashSet<Coord2D>) -> HashSet<Coord2D> {
-    // let ret = HashSet::with_capacity(steps.capacity() * 2);
-    steps
-        .into_iter()
-        .flat_map(|coord| {
-            grid.get_neighbour_coords_filtered(coord, false, |_, item| {
-                matches!(item, GridState::Garden)
-            })
-        })
-        .collect()
-}
-
-pub fn submain(data: crate::DataIn, num_steps: u32) -> crate::AoCResult<String> {
+fn load(data: crate::DataIn) -> crate::AoCResult<(TiledGrid<Grid<GridState>>, BigCoord2D)> {
     let mut grid: Grid<GridState> = Grid::new_from_chars(data)?;
-    // println!("{grid}");
     let start = grid
         .find(|(_, item)| matches!(item, GridState::Start))
-        .expect("Must have a start");
+        .ok_or(AoCError::new("Must have a start"))?;
     grid.set(start, GridState::Garden);
+    let start = BigCoord2D {
+        x: start.x.into(),
+        y: start.y.into(),
+    };
+    Ok((TiledGrid::new(grid), start))
+}
 
-    let mut steps = HashSet::new();
-    steps.insert(start);
-    for _ in 0..num_steps {
-        steps = big_steppe(&grid, steps);
-    }
-    // steps.into_iter().for_each(|c| {
-    //     grid.set(c, GridState::Occcupied);
-    // });
-    // println!("{grid}");
+/// Every plot that can be reached in at most `steps` can be reached in
+/// exactly `steps` if it's an even number of steps short, since the elf can
+/// just step back and forth
+fn count_plots(grid: &TiledGrid<Grid<GridState>>, start: BigCoord2D, steps: u64) -> usize {
+    reachable_within_unbounded(grid, [start], Connectivity::Orthogonal, steps, |_, item| {
+        !matches!(item, GridState::Rock)
+    })
+    .iter()
+    .filter(|(_, distance)| distance % 2 == steps % 2)
+    .count()
+}
 
-    let ret = steps.len();
-    Ok(ret.to_string())
+/// The real inputs have nothing in the way along the start's row and column,
+/// so every time the elf walks another whole grid's width the plots grow
+/// quadratically
+fn is_clear(grid: &TiledGrid<Grid<GridState>>, start: BigCoord2D) -> bool {
+    let (width, height) = grid.tile_size();
+    width == height
+        && (0..width).all(|i| {
+            [(i, start.y), (start.x, i)]
+                .into_iter()
+                .all(|coord| !matches!(grid.get(&coord.into()), Some(GridState::Rock)))
+        })
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     submain(data, 64)
 }
 
+#[example(part = 1)]
 pub fn part_1_example(data: crate::DataIn) -> crate::AoCResult<String> {
     submain(data, 6)
 }
 
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "21",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1_example
-    },
-    part_2: None,
-});
+pub fn submain(data: crate::DataIn, num_steps: u64) -> crate::AoCResult<String> {
+    let (grid, start) = load(data)?;
+    let ret = count_plots(&grid, start, num_steps);
+    Ok(ret.to_string())
+}
+
+#[solution(part = 2, params(steps = 26501365))]
+#[example(part = 2, params(steps = 100))]
+pub fn part_2(data: crate::DataIn, steps: u64) -> crate::AoCResult<String> {
+    let (grid, start) = load(data)?;
+    let width = grid.tile_size().0 as u64;
+    let grids = steps / width;
+    if grids <= 2 || !is_clear(&grid, start) {
+        let ret = count_plots(&grid, start, steps);
+        return Ok(ret.to_string());
+    }
+
+    let [a, b, c] = [0, 1, 2].map(|n| count_plots(&grid, start, steps % width + n * width) as u64);
+    let first = b - a;
+    let second = c + a - 2 * b;
+    let ret = a + grids * first + grids * (grids - 1) / 2 * second;
+    Ok(ret.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::submain;
+    use crate::AoCData;
+
+    const EXAMPLE: [&str; 11] = [
+        "...........",
+        ".....###.#.",
+        ".###.##..#.",
+        "..#.#...#..",
+        "....#.#....",
+        ".##..S####.",
+        ".##..#...#.",
+        ".......##..",
+        ".##.#.####.",
+        ".##..##.##.",
+        "...........",
+    ];
+
+    #[test]
+    fn test_example_steps() {
+        for (steps, expected) in [(6, 16), (10, 50), (50, 1594), (100, 6536)] {
+            let data: AoCData = EXAMPLE.into_iter().map(str::to_owned).collect();
+            let ret = submain(data.into_iter(), steps).unwrap();
+            assert_eq!(ret, expected.to_string(), "{steps} steps");
+        }
+    }
+}
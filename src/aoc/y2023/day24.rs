@@ -7,11 +7,12 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
-use std::{fmt::Display, str::FromStr};
+use std::fmt::Display;
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
-use crate::{AoCError, AoCResult};
+use crate::AoCResult;
 
 #[derive(Debug, PartialEq, Clone, Copy, Default)]
 struct CoordF3D {
@@ -20,28 +21,6 @@ struct CoordF3D {
     z: f64,
 }
 
-impl FromStr for CoordF3D {
-    type Err = AoCError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x, y, z) = s
-            .split(',')
-            .map(|c| c.trim().parse())
-            .collect_tuple()
-            .ok_or_else(|| {
-                AoCError::new(format!(
-                    "String '{s}' has {} commas, expected 2",
-                    s.chars().filter(|c| *c == ',').count()
-                ))
-            })?;
-
-        Ok(Self {
-            x: x?,
-            y: y?,
-            z: z?,
-        })
-    }
-}
-
 impl std::ops::Mul<f64> for CoordF3D {
     type Output = Self;
 
@@ -72,23 +51,17 @@ struct Hailstone {
     dir: CoordF3D,
 }
 
-impl FromStr for Hailstone {
-    type Err = AoCError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start, dir) = s.split_once('@').ok_or_else(|| {
-            AoCError::new(format!("Expected {s} to have a '@' in it, but it didn't"))
-        })?;
-
-        Ok(Self {
-            start: start.parse()?,
-            dir: dir.parse()?,
-        })
-    }
-}
-
 fn stones(data: crate::DataIn) -> AoCResult<Vec<Hailstone>> {
-    data.map(|line| line.parse()).try_collect()
+    data.ints_array()
+        .map_ok(|[x, y, z, dx, dy, dz]| Hailstone {
+            start: CoordF3D { x, y, z },
+            dir: CoordF3D {
+                x: dx,
+                y: dy,
+                z: dz,
+            },
+        })
+        .try_collect()
 }
 
 fn collisions(hailstones: impl Iterator<Item = Hailstone>) -> impl Iterator<Item = CoordF3D> {
@@ -137,25 +110,17 @@ fn collisions(hailstones: impl Iterator<Item = Hailstone>) -> impl Iterator<Item
 }
 
 #[allow(dead_code)]
+#[solution(part = 1)]
 pub fn part_1(_data: crate::DataIn) -> AoCResult<String> {
     // hailstones.iter().for_each(|stone| println!("{stone:?}"));
     let ret = 0;
     Ok(ret.to_string())
 }
 
+#[example(part = 1)]
 pub fn part_1_example(data: crate::DataIn) -> AoCResult<String> {
     let hailstones = stones(data)?;
     let collisions = collisions(hailstones.into_iter());
 
     Ok(collisions.inspect(|c| println!("{c}")).count().to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "24",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1_example
-    },
-    part_2: None,
-});
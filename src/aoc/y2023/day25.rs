@@ -9,6 +9,7 @@
 
 use std::{collections::HashMap, str::FromStr};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::AoCError;
@@ -45,6 +46,7 @@ impl Node {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let nodes: Vec<Node> = data.map(|line| line.parse()).try_collect()?;
     let mut nodes: HashMap<String, Node> = nodes
@@ -79,13 +81,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let ret = 0;
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2023",
-    day: "25",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
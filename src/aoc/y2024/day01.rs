@@ -7,15 +7,20 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
+use crate::{AoCResult, Context};
+
+fn parse_line(line: &str) -> AoCResult<(u64, u64)> {
+    let (a, b) = line.split_once(' ').context("line must be splittable")?;
+    Ok((a.trim().parse()?, b.trim().parse()?))
+}
+
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
-    let (mut a, mut b): (Vec<_>, Vec<_>) = data
-        .map(|line| -> (u64, u64) {
-            let (a, b) = line.split_once(' ').expect("line must be splittable");
-            (a.trim().parse().unwrap(), b.trim().parse().unwrap())
-        })
-        .unzip();
+    let pairs: Vec<_> = data.map_lines(parse_line).try_collect()?;
+    let (mut a, mut b): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
     a.sort();
     b.sort();
     assert_eq!(a.len(), b.len(), "lists must have the same length!");
@@ -27,13 +32,10 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
         .to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
-    let (a, b): (Vec<_>, Vec<_>) = data
-        .map(|line| -> (u64, u64) {
-            let (a, b) = line.split_once(' ').expect("line must be splittable");
-            (a.trim().parse().unwrap(), b.trim().parse().unwrap())
-        })
-        .unzip();
+    let pairs: Vec<_> = data.map_lines(parse_line).try_collect()?;
+    let (a, b): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
 
     let counts = b.into_iter().counts();
     Ok(a.into_iter()
@@ -42,16 +44,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .unwrap()
         .to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "1",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
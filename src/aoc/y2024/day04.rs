@@ -7,8 +7,11 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
+
 use crate::{CharGrid, CommonGrid, Coord2D, Grid};
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let grid: Grid<char> = Grid::new_from_chars(data).unwrap();
     let ret = grid
@@ -36,6 +39,7 @@ const DOWN_LEFT: Coord2D = Coord2D { x: -1, y: 1 };
 const UP_RIGHT: Coord2D = Coord2D { x: 1, y: -1 };
 const DOWN_RIGHT: Coord2D = Coord2D { x: 1, y: 1 };
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let grid: Grid<char> = Grid::new_from_chars(data).unwrap();
     let ret = grid
@@ -58,16 +62,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .count();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "4",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -9,10 +9,12 @@
 
 use std::collections::{HashMap, HashSet};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, partition_input};
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let (setup, puzzle) = partition_input(data);
 
@@ -52,6 +54,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let (setup, puzzle) = partition_input(data);
 
@@ -106,16 +109,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .fold_ok(0, std::ops::Add::add)?;
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "5",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -10,6 +10,7 @@
 use std::collections::HashSet;
 use std::fmt::Display;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::symbols;
@@ -48,7 +49,9 @@ impl Display for GridState {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
+    data.check_rectangular()?;
     let mut grid = Grid::new_from_chars(data)?;
     let mut guardpos = grid
         .find(|(_, v)| matches!(v, GridState::Guard))
@@ -88,7 +91,9 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
+    data.check_rectangular()?;
     let mut grid = Grid::new_from_chars(data)?;
     let mut guardpos = grid
         .find(|(_, v)| matches!(v, GridState::Guard))
@@ -148,16 +153,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .count();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "6",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -9,6 +9,7 @@
 
 use std::collections::HashMap;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::AoCError;
@@ -60,23 +61,12 @@ fn main(
     Ok(ret.to_string())
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     main(data, &OPERATIONS[0..2])
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     main(data, &OPERATIONS)
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "7",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 use num::Integer;
 
@@ -22,6 +23,7 @@ fn printarr(values: &[(usize, Vec<usize>)]) {
     println!();
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut blanks: Vec<(usize, usize)> = Vec::new();
     let mut blank_idx = 0_usize;
@@ -95,6 +97,8 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
+#[example(part = 2, with = crate::multi_line_example)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let mut blanks: Vec<(usize, usize)> = Vec::new();
     let mut block_id = 0_usize;
@@ -173,16 +177,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
         .sum();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "9",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| crate::multi_line_example(data, part_2),
-    }),
-});
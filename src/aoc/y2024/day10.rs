@@ -10,6 +10,8 @@
 use std::collections::{HashSet, VecDeque};
 use std::fmt::Display;
 
+use aoc_macros::solution;
+
 use crate::{AoCError, CharGrid, CommonGrid, Grid};
 
 struct Height(u32);
@@ -48,6 +50,7 @@ impl Height {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let grid: Grid<Height> = Grid::new_from_chars(data)?;
 
@@ -82,6 +85,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let grid: Grid<Height> = Grid::new_from_chars(data)?;
 
@@ -109,16 +113,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "10",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -7,10 +7,10 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
-use lazy_static::lazy_static;
-use regex::Regex;
 
+use crate::utils::tokens;
 use crate::{AoCError, AoCResult};
 
 type Stupid = i128;
@@ -29,24 +29,11 @@ impl ClawMachine {
         let (a, b, prize) = data
             .next_tuple()
             .ok_or(AoCError::new("Not enough lines for a claw machine!"))?;
-        lazy_static! {
-            static ref BUTTON_RE: Regex = Regex::new(r"^Button .: X\+(\d+), Y\+(\d+)$").unwrap();
-            static ref PRIZE_RE: Regex = Regex::new(r"^Prize: X=(\d+), Y=(\d+)").unwrap();
-        }
-        let matches_a = BUTTON_RE
-            .captures(&a)
-            .ok_or_else(AoCError::new_from_regex(&a, &BUTTON_RE))?;
-        let matches_b = BUTTON_RE
-            .captures(&b)
-            .ok_or_else(AoCError::new_from_regex(&b, &BUTTON_RE))?;
-        let matches_prize = PRIZE_RE
-            .captures(&prize)
-            .ok_or_else(AoCError::new_from_regex(&prize, &PRIZE_RE))?;
 
         Ok(Self {
-            a: [matches_a[1].parse()?, matches_a[2].parse()?],
-            b: [matches_b[1].parse()?, matches_b[2].parse()?],
-            prize: [matches_prize[1].parse()?, matches_prize[2].parse()?],
+            a: tokens::ints_array(&a)?,
+            b: tokens::ints_array(&b)?,
+            prize: tokens::ints_array(&prize)?,
         })
     }
 
@@ -81,6 +68,7 @@ impl ClawMachine {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut data = data.peekable();
 
@@ -103,6 +91,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut data = data.peekable();
 
@@ -121,16 +110,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "13",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -7,67 +7,29 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
-use std::{cmp::Ordering, collections::HashSet, fmt::Display, str::FromStr};
+use std::{cmp::Ordering, collections::HashSet, fmt::Display};
 
+use aoc_macros::{AocParse, example, solution};
 use itertools::Itertools;
-use lazy_static::lazy_static;
-use regex::Regex;
 
-use crate::{AoCError, AoCResult, CommonGrid, Coord2D, Grid, symbols};
+use crate::{AoCError, AoCResult, Coord2D, WrappingGrid, symbols};
 
 const ITERATIONS: usize = 100;
-// these are exclusive
-const MAX_X: i32 = 101;
-const MAX_Y: i32 = 103;
-const MAX_X_EXAMPLE: i32 = 11;
-const MAX_Y_EXAMPLE: i32 = 7;
 
+#[derive(AocParse)]
+#[aoc(pattern = "p={pos} v={vel}")]
 struct Robot {
     pos: Coord2D,
     vel: Coord2D,
 }
 
-impl FromStr for Robot {
-    type Err = AoCError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"^p=(\d+,\d+) v=(-?\d+,-?\d+)$").unwrap();
-        }
-        let matches = RE
-            .captures(s)
-            .ok_or_else(|| AoCError::new(format!("line {s} does not match regex!")))?;
-
-        Ok(Self {
-            pos: matches[1].parse()?,
-            vel: matches[2].parse()?,
-        })
-    }
-}
-
 impl Robot {
-    fn simulate(&mut self, max_x: i32, max_y: i32) {
-        // print!("I'm at {} and I'm moving {} ", self.pos, self.vel);
-        self.pos += self.vel;
-        // print!("to {}", self.pos);
-        if self.pos.x >= max_x {
-            self.pos.x -= max_x;
-            // print!(" x too big!!");
-        } else if self.pos.x < 0 {
-            self.pos.x += max_x;
-            // print!(" x too smol!!");
-        }
-        if self.pos.y >= max_y {
-            self.pos.y -= max_y;
-            // print!(" y too big!!");
-        } else if self.pos.y < 0 {
-            self.pos.y += max_y;
-            // print!(" y too smol!!");
-        }
-        // println!();
+    fn simulate(&mut self, area: &WrappingGrid<RobotCount>) {
+        self.pos = area.wrap(&(self.pos + self.vel));
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 struct RobotCount(usize);
 
 impl Display for RobotCount {
@@ -80,31 +42,34 @@ impl Display for RobotCount {
     }
 }
 
-fn debug_grid(robots: &[Robot], max_x: i32, max_y: i32) {
-    let mut debuggrid: Grid<RobotCount> = Grid::new(
-        //
-        u32::try_from(max_x).unwrap(),
-        u32::try_from(max_y).unwrap(),
-    );
+fn new_area(max_x: i32, max_y: i32) -> WrappingGrid<RobotCount> {
+    WrappingGrid::new(u32::try_from(max_x).unwrap(), u32::try_from(max_y).unwrap())
+}
+
+fn debug_grid(robots: &[Robot], mut area: WrappingGrid<RobotCount>) {
     for robot in robots.iter() {
-        debuggrid.get_mut(&robot.pos).unwrap().0 += 1;
+        area[robot.pos].0 += 1;
     }
-    println!("{debuggrid:#}");
+    println!("{area:#}");
 }
 
+// the maximums are exclusive
+#[solution(part = 1, params(max_x = 101, max_y = 103))]
+#[example(part = 1, params(max_x = 11, max_y = 7))]
 fn part_1(data: crate::DataIn, max_x: i32, max_y: i32) -> AoCResult<String> {
     // L + gridless
     let mut robots: Vec<Robot> = data.map(|line| line.parse()).try_collect()?;
+    let area = new_area(max_x, max_y);
     // println!("Initial State");
-    // debug_grid(&robots, max_x, max_y);
+    // debug_grid(&robots, area.clone());
     for _i in 0..ITERATIONS {
         for robot in robots.iter_mut() {
-            robot.simulate(max_x, max_y);
+            robot.simulate(&area);
         }
         // println!("After {_i} seconds");
-        // debug_grid(&robots, max_x, max_y);
+        // debug_grid(&robots, area.clone());
     }
-    debug_grid(&robots, max_x, max_y);
+    debug_grid(&robots, area);
     // quadrentify
     let mid_x = max_x / 2;
     let mid_y = max_y / 2;
@@ -136,14 +101,16 @@ fn robots_unique(robots: &[Robot]) -> bool {
     robots.iter().all(|robot| seen.insert(robot.pos))
 }
 
-fn part_2(data: crate::DataIn) -> AoCResult<String> {
+#[solution(part = 2, params(max_x = 101, max_y = 103), no_example, interactive)]
+fn part_2(data: crate::DataIn, max_x: i32, max_y: i32) -> AoCResult<String> {
     let mut robots: Vec<Robot> = data.map(|line| line.parse()).try_collect()?;
+    let area = new_area(max_x, max_y);
     for i in 1.. {
         for robot in robots.iter_mut() {
-            robot.simulate(MAX_X, MAX_Y);
+            robot.simulate(&area);
         }
         if robots_unique(&robots) {
-            debug_grid(&robots, MAX_X, MAX_Y);
+            debug_grid(&robots, area.clone());
             println!("Correct (@ {i})?");
             if paws()? {
                 return Ok(i.to_string());
@@ -152,16 +119,3 @@ fn part_2(data: crate::DataIn) -> AoCResult<String> {
     }
     unreachable!()
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "14",
-    part_1: crate::AoCPart {
-        main: |data| part_1(data, MAX_X, MAX_Y),
-        example: |data| part_1(data, MAX_X_EXAMPLE, MAX_Y_EXAMPLE)
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: crate::no_example
-    }),
-});
@@ -9,9 +9,10 @@
 
 use std::fmt::Display;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
-use crate::{AoCError, CharGrid, CommonGrid, Direction, Grid, partition_input, symbols};
+use crate::{AoCError, AoCResult, CharGrid, CommonGrid, Direction, Grid, symbols};
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy)]
@@ -47,13 +48,16 @@ impl Display for GridState {
     }
 }
 
-pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
-    let (griddata, instructions) = partition_input(data);
-    let mut grid: Grid<GridState> = Grid::new_from_chars(griddata)?;
-    let instructions: Vec<Direction> = instructions
-        .flat_map(|line| -> Vec<_> { line.chars().collect() })
+fn parse_instructions(data: crate::DataIn) -> AoCResult<Vec<Direction>> {
+    data.flat_map(|line| -> Vec<_> { line.chars().collect() })
         .map(char::try_into)
-        .try_collect()?;
+        .try_collect()
+}
+
+#[solution(part = 1)]
+pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
+    let (mut grid, instructions): (Grid<GridState>, _) =
+        data.parse_sections((Grid::new_from_chars, parse_instructions))?;
 
     println!("{grid}");
 
@@ -104,13 +108,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
         .sum();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "15",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
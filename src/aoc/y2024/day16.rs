@@ -8,6 +8,8 @@ use std::fmt::Display;
 // You should have received a copy of the Licence along with this work. If not, see:
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
+use aoc_macros::solution;
+
 use crate::utils::astar::{AStarProvider, a_star};
 use crate::{
     AoCError, AoCResult, CharGrid, CommonGrid, Coord2D, Coordinate, Direction, Grid,
@@ -129,6 +131,7 @@ impl AStarProvider for AStarImpl {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let provider = AStarImpl::new_from_chars(data)?;
     // why have I done this to myself
@@ -156,13 +159,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "16",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, AoCResult};
@@ -50,7 +51,7 @@ struct Computer {
     pc: usize,
 }
 
-fn parse_register(line: String) -> AoCResult<Integer> {
+fn parse_register(line: &str) -> AoCResult<Integer> {
     let (_, value) = line
         .split_once(": ")
         .ok_or(AoCError::new(format!("colon missing from '{line}'!")))?;
@@ -58,21 +59,18 @@ fn parse_register(line: String) -> AoCResult<Integer> {
 }
 
 impl Computer {
-    fn new(mut data: crate::DataIn) -> AoCResult<Self> {
-        let (line_reg_a, line_reg_b, line_reg_c, _, line_program) = data
-            .next_tuple()
-            .ok_or(AoCError::new("Not enough lines for the computer!"))?;
+    fn new(data: crate::DataIn) -> AoCResult<Self> {
+        let [registers, program] = data.sections()?;
+        let (reg_a, reg_b, reg_c) = registers
+            .map_lines(parse_register)
+            .collect_tuple()
+            .ok_or(AoCError::new("Expected 3 registers for the computer!"))?;
 
         Ok(Self {
-            reg_a: parse_register(line_reg_a)?,
-            reg_b: parse_register(line_reg_b)?,
-            reg_c: parse_register(line_reg_c)?,
-            program: {
-                let (_, value) = line_program.split_once(": ").ok_or(AoCError::new(format!(
-                    "colon missing from '{line_program}'!"
-                )))?;
-                value.split(',').map(str::parse).try_collect()?
-            },
+            reg_a: reg_a?,
+            reg_b: reg_b?,
+            reg_c: reg_c?,
+            program: program.all_ints().try_collect()?,
             pc: 0,
         })
     }
@@ -140,18 +138,9 @@ impl Computer {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let computer = Computer::new(data)?;
     let ret = computer.computate().into_iter().join(",");
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "17",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
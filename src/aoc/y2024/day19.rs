@@ -9,6 +9,7 @@
 
 use std::{collections::HashMap, str::FromStr};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::AoCError;
@@ -29,6 +30,7 @@ impl FromStr for Towel {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let towels: Vec<Towel> = data
         .next()
@@ -78,6 +80,7 @@ pub fn part_1(mut data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     let towels: Vec<Towel> = data
         .next()
@@ -128,16 +131,3 @@ pub fn part_2(mut data: crate::DataIn) -> crate::AoCResult<String> {
     // let ret = data.count();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "19",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -6,145 +6,97 @@
 // You should have received a copy of the Licence along with this work. If not, see:
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
-use std::fmt::Display;
-
-use itertools::Itertools;
-
-use crate::utils::astar::{AStarProvider, a_star};
-use crate::{AoCError, AoCResult, CharGrid, Coord2D, Coordinate, Grid, GridState};
-
-const TIME_TO_SAVE_PART_1: usize = 100;
-const TIME_TO_SAVE_PART_2: usize = 74;
-
-type AStarID = Coord2D;
-
-#[derive(Debug)]
-struct AStarImpl {
-    grid: Grid<GridState>,
-    start: Coord2D,
-    end: Coord2D,
-}
-
-impl AStarImpl {
-    fn new_from_chars(data: crate::DataIn) -> AoCResult<Self> {
-        let grid = Grid::new_from_chars(data)?;
-        let start = grid
-            .find(|(_, v)| matches!(v, GridState::Start))
-            .ok_or(AoCError::new("must have a start"))?;
-        let end = grid
-            .find(|(_, v)| matches!(v, GridState::End))
-            .ok_or(AoCError::new("must have an end"))?;
-
-        Ok(Self { grid, start, end })
-    }
-
-    fn get_start(&self) -> AStarID {
-        self.start
-    }
-}
-
-impl Display for AStarImpl {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.grid.fmt(f)
-    }
-}
-
-impl AStarProvider for AStarImpl {
-    type IDType = AStarID;
-
-    fn get_neighbours(&self, coord: &Self::IDType) -> Box<dyn Iterator<Item = Self::IDType> + '_> {
-        Box::new(
-            self.grid
-                .get_neighbour_coords_filtered(*coord, false, |_, state| {
-                    matches!(state, GridState::Void | GridState::End)
-                }),
-        )
-    }
-
-    fn heuristic(&self, coord: &Self::IDType) -> u64 {
-        self.end.distance(coord).try_into().unwrap()
-    }
-
-    fn cost(&self, _coord: &Self::IDType) -> u64 {
-        1
-    }
-
-    fn is_end(&self, coord: &Self::IDType) -> bool {
-        coord == &self.end
-    }
-}
-
-pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
-    let provider = AStarImpl::new_from_chars(data)?;
-    log::debug!("\n{}", provider.grid);
-    let start = provider.get_start();
-    let route = a_star(provider, start);
-    assert!(!route.is_empty());
-    let ret: usize = route
+use aoc_macros::solution;
+
+use crate::utils::pathfinding::bfs;
+use crate::{AoCError, AoCResult, CharGrid, Connectivity, Coord2D, Grid, GridState};
+
+const TIME_TO_SAVE_PART_1: u64 = 100;
+const TIME_TO_SAVE_PART_2: u64 = 100;
+
+fn count_cheats(data: crate::DataIn, cheat_length: i32, time_to_save: u64) -> AoCResult<usize> {
+    let grid: Grid<GridState> = Grid::new_from_chars(data)?;
+    log::debug!("\n{grid}");
+    let start = grid
+        .find(|(_, v)| matches!(v, GridState::Start))
+        .ok_or(AoCError::new("must have a start"))?;
+    let end = grid
+        .find(|(_, v)| matches!(v, GridState::End))
+        .ok_or(AoCError::new("must have an end"))?;
+
+    let passable = |_: &Coord2D, state: &GridState| !matches!(state, GridState::Block);
+    let from_start = bfs(&grid, [start], Connectivity::Orthogonal, passable);
+    let to_end = bfs(&grid, [end], Connectivity::Orthogonal, passable);
+    let fair_time = from_start
+        .get(&end)
+        .ok_or(AoCError::new("can't get from the start to the end"))?;
+
+    Ok(from_start
         .iter()
-        .enumerate()
-        .map(|(i, coord)| {
-            route
-                .iter()
-                .enumerate()
-                .skip(i + TIME_TO_SAVE_PART_1)
-                .filter(|(_, other)| coord.distance(other) == 2)
-                // I'm not entirely sure why this is necessary but it is
-                .filter(|(j, _)| (j - i - 2) >= TIME_TO_SAVE_PART_1)
+        .map(|(coord, before)| {
+            (-cheat_length..=cheat_length)
+                .flat_map(|y| {
+                    let width = cheat_length - y.abs();
+                    (-width..=width).map(move |x| Coord2D { x, y })
+                })
+                .filter(|offset| {
+                    let cheat_time = (offset.x.abs() + offset.y.abs()) as u64;
+                    to_end.get(&(*coord + *offset)).is_some_and(|after| {
+                        before + cheat_time + after + time_to_save <= fair_time
+                    })
+                })
                 .count()
         })
-        .sum();
+        .sum())
+}
+
+#[solution(part = 1)]
+pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
+    let ret = count_cheats(data, 2, TIME_TO_SAVE_PART_1)?;
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
-    let provider = AStarImpl::new_from_chars(data)?;
-    let start = provider.get_start();
-    let route = a_star(provider, start);
-    assert!(!route.is_empty());
-    let ret: usize = route
-        .iter()
-        .enumerate()
-        .map(|(i, coord)| {
-            route
-                .iter()
-                .enumerate()
-                .skip(i + TIME_TO_SAVE_PART_2)
-                .filter_map(|(j, other)| {
-                    let distance = coord.distance(other);
-                    if (2..=20).contains(&distance) {
-                        // Some((j, distance))
-                        Some(j - i - (distance as usize))
-                    } else {
-                        None
-                    }
-                })
-                .sorted()
-                .inspect(|v| log::trace!("{v}"))
-                .filter(|v| *v >= TIME_TO_SAVE_PART_2)
-                // .inspect(|(j, distance)| println!("{}", j - i - (*distance as usize)))
-                // I'm not entirely sure why this is necessary but it is
-                // .filter(|(j, distance)| (j - i - (*distance as usize)) + 1 >= TIME_TO_SAVE)
-                .count()
-        })
-        .inspect(|v| {
-            if *v > 0 {
-                log::trace!("   {v}")
-            }
-        })
-        .sum();
+    let ret = count_cheats(data, 20, TIME_TO_SAVE_PART_2)?;
     Ok(ret.to_string())
 }
 
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "20",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
+#[cfg(test)]
+mod test {
+    use super::count_cheats;
+    use crate::AoCData;
+
+    const EXAMPLE: [&str; 15] = [
+        "###############",
+        "#...#...#.....#",
+        "#.#.#.#.#.###.#",
+        "#S#...#.#.#...#",
+        "#######.#.#.###",
+        "#######.#.#...#",
+        "#######.#.###.#",
+        "###..E#...#...#",
+        "###.#######.###",
+        "#...###...#...#",
+        "#.#####.#.###.#",
+        "#.#...#.#.#...#",
+        "#.#.#.#.#.#.###",
+        "#...#...#...###",
+        "###############",
+    ];
+
+    #[test]
+    fn test_example_cheats() {
+        for (cheat_length, time_to_save, expected) in [
+            (2, 64, 1),
+            (2, 20, 5),
+            (2, 2, 44),
+            (20, 76, 3),
+            (20, 50, 285),
+        ] {
+            let data: AoCData = EXAMPLE.into_iter().map(str::to_owned).collect();
+            let ret = count_cheats(data.into_iter(), cheat_length, time_to_save).unwrap();
+            assert_eq!(ret, expected, "{cheat_length} ps saving {time_to_save}");
+        }
+    }
+}
@@ -9,6 +9,7 @@
 
 use std::{collections::HashMap, fmt::Display};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 
@@ -255,6 +256,7 @@ fn expandinate(movements: Vec<KB>) -> Vec<KB> {
         .collect()
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let keygrid: InfGrid<_> = [
         NumericKeypad::One,
@@ -307,13 +309,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "21",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 type Aaaa = u64;
@@ -24,6 +25,7 @@ fn evolvinate(mut value: Aaaa) -> Aaaa {
     value
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let ret = data
         .map(|line| line.parse())
@@ -31,13 +33,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
         .try_fold(0, |acc, value| value.map(|value| acc + value))?;
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "22",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
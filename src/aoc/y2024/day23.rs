@@ -9,6 +9,7 @@
 
 use std::collections::{HashMap, HashSet};
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 type ComputerID = String;
@@ -20,6 +21,7 @@ fn hash_key(a: &ComputerID, b: &ComputerID, c: &ComputerID) -> HashKey {
     ret.map(String::to_owned)
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let computers: HashMap<ComputerID, HashSet<ComputerID>> = {
         data.map(|line| {
@@ -57,6 +59,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let computers: HashMap<ComputerID, HashSet<ComputerID>> = {
         data.map(|line| {
@@ -99,16 +102,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "23",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    }),
-});
@@ -10,6 +10,7 @@
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -159,6 +160,7 @@ fn drain_updates(
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let (wires, gates) = partition_input(data);
 
@@ -196,14 +198,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let ret = u64::from_str_radix(&ret, 2).unwrap();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "24",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    // Part 2 was done non-programatically
-    part_2: None,
-});
@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::{Either, Itertools};
 
 use crate::{AoCError, AoCResult, InputPartitioner};
@@ -52,6 +53,7 @@ impl Pinnable {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let (locks, keys): (Vec<_>, Vec<_>) = InputPartitioner::new(data, |line| !line.is_empty())
         .map(|lines| Pinnable::new(lines).unwrap())
@@ -74,13 +76,3 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
         .sum();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2024",
-    day: "25",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None,
-});
@@ -7,17 +7,10 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
+
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let ret = data.count();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "0",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: None
-});
@@ -10,6 +10,7 @@
 use std::fmt::Display;
 use std::str::FromStr;
 
+use aoc_macros::solution;
 use num::Integer;
 
 use crate::AoCError;
@@ -109,6 +110,7 @@ impl Dial {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret = 0;
     let mut dial: Dial = Default::default();
@@ -123,6 +125,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ret = 0;
     let mut dial: Dial = Default::default();
@@ -133,20 +136,6 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     log::info!("{dial}");
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "1",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
-
 #[cfg(test)]
 mod test {
     use super::{Dial, Instruction};
@@ -9,6 +9,7 @@
 
 use std::ops::RangeInclusive;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use num::Integer;
 
@@ -29,6 +30,7 @@ fn rangify(data: crate::DataIn) -> AoCResult<Vec<RangeInclusive<u64>>> {
         .inspect(|ranges| log::debug!("Collected ranges: {ranges:?}"))
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let ret: u64 = rangify(data)?
         .into_iter()
@@ -51,6 +53,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let ret: u64 = rangify(data)?
         .into_iter()
@@ -78,16 +81,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "2",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
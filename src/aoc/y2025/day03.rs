@@ -7,6 +7,7 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::AoCError;
@@ -71,27 +72,16 @@ fn process_line_p2(line: String) -> crate::AoCResult<u64> {
     best.parse().map_err(AoCError::new_from_parseerror)
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let banks: Vec<_> = data.map(process_line_p1).try_collect()?;
     let ret: u64 = banks.into_iter().sum();
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let banks: Vec<_> = data.map(process_line_p2).try_collect()?;
     let ret: u64 = banks.into_iter().sum();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "3",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
@@ -9,9 +9,10 @@
 
 use std::fmt::Display;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
-use crate::{AoCError, CharGrid, CommonGrid, Coord2D, Grid, symbols};
+use crate::{AoCError, CharGrid, CommonGrid, Coord2D, DenseGrid, symbols};
 
 enum GridState {
     Empty,
@@ -39,28 +40,30 @@ impl Display for GridState {
     }
 }
 
-fn get_forkable(grid: &Grid<GridState>) -> impl Iterator<Item = Coord2D> {
+fn get_forkable(grid: &DenseGrid<GridState>) -> impl Iterator<Item = Coord2D> {
     grid.iter()
         .filter(|(coord, state)| {
             matches!(state, GridState::Roll)
                 && grid
-                    .get_neighbours(**coord, true)
+                    .get_neighbours(*coord, true)
                     .filter(|(_, state)| matches!(state, GridState::Roll))
                     .count()
                     < 4
         })
-        .map(|(coord, _)| *coord)
+        .map(|(coord, _)| coord)
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
-    let grid = Grid::<GridState>::new_from_chars(data)?;
+    let grid = DenseGrid::<GridState>::new_from_chars(data)?;
     log::debug!("{grid}");
     let ret = get_forkable(&grid).count();
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
-    let mut grid = Grid::<GridState>::new_from_chars(data)?;
+    let mut grid = DenseGrid::<GridState>::new_from_chars(data)?;
     let mut total_removed = 0;
 
     loop {
@@ -77,16 +80,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     }
     Ok(total_removed.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "4",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
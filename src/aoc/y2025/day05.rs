@@ -9,6 +9,7 @@
 
 use std::ops::RangeInclusive;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, AoCResult, partition_input};
@@ -16,6 +17,7 @@ use crate::{AoCError, AoCResult, partition_input};
 // This may have to become a u128...
 type Num = u64;
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let (ranges, ingredients) = partition_input(data);
 
@@ -37,6 +39,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut ranges: Vec<_> = data
         .take_while(|line| !line.is_empty())
@@ -78,16 +81,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "5",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
@@ -9,6 +9,7 @@
 
 use std::str::FromStr;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, AoCResult};
@@ -52,6 +53,7 @@ impl FromStr for Input {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let rows: Vec<Vec<Input>> = data
         .map(|line| {
@@ -94,6 +96,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut lines = data.collect_vec();
 
@@ -128,16 +131,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "6",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
@@ -10,6 +10,7 @@ use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 
 use aoc_macros::VoidState;
+use aoc_macros::solution;
 
 use crate::{AoCError, CharGrid, CommonGrid, Coord2D, Direction, SparseGrid, symbols};
 
@@ -47,6 +48,7 @@ impl TryFrom<char> for GridState {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let mut grid = SparseGrid::<GridState>::new_from_chars(
         data
@@ -91,6 +93,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let grid = SparseGrid::<GridState>::new_from_chars(
         data
@@ -123,16 +126,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
         .sum();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "7",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
@@ -9,6 +9,7 @@
 
 use std::{cmp::Ordering, collections::HashSet};
 
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
 use crate::Coord3D;
@@ -29,6 +30,8 @@ fn get_distances(coords: Vec<Coord3D>) -> Vec<(i64, (Coord3D, Coord3D))> {
         .collect()
 }
 
+#[solution(part = 1, params(num_connections = 1000))]
+#[example(part = 1, params(num_connections = 10))]
 pub fn part_1(data: crate::DataIn, num_connections: usize) -> crate::AoCResult<String> {
     let coords: Vec<Coord3D> = data.map(|line| line.parse()).try_collect()?;
     let distances = get_distances(coords);
@@ -91,6 +94,7 @@ pub fn part_1(data: crate::DataIn, num_connections: usize) -> crate::AoCResult<S
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let coords: Vec<Coord3D> = data.map(|line| line.parse()).try_collect()?;
     let mut circuits: Vec<HashSet<Coord3D>> = coords
@@ -162,16 +166,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     //     .product();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "8",
-    part_1: crate::AoCPart {
-        main: |data| part_1(data, 1000),
-        example: |data| part_1(data, 10)
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
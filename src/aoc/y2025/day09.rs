@@ -9,10 +9,12 @@
 
 use std::fmt::Display;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 
 use crate::{AoCError, BigCoord2D, CommonGrid, Coordinate, symbols};
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let coords: Vec<BigCoord2D> = data.map(|line| line.parse()).try_collect()?;
 
@@ -35,6 +37,7 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let coords = {
         let mut coords: Vec<BigCoord2D> = data.map(|line| line.parse()).try_collect()?;
@@ -122,16 +125,3 @@ pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
 
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "9",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2,
-    })
-});
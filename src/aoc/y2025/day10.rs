@@ -12,6 +12,7 @@ use std::cmp::Ordering;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use aoc_macros::solution;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -267,6 +268,7 @@ impl Machine {
     }
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let machines: Vec<Machine> = data.parse().try_collect()?;
     let ret: usize = machines
@@ -279,21 +281,9 @@ pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     Ok(ret.to_string())
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let machines: Vec<Machine> = data.parse().try_collect()?;
     let ret: usize = machines.into_iter().map(|machine| machine.part_2()).sum();
     Ok(ret.to_string())
 }
-
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "10",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: part_1
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: part_2
-    })
-});
@@ -9,10 +9,10 @@
 
 use std::collections::HashMap;
 
-use cached::{UnboundCache, cached_key};
+use aoc_macros::{example, solution};
 use itertools::Itertools;
 
-use crate::AoCError;
+use crate::{AoCError, Memo};
 
 type Key = u32;
 type Store = HashMap<Key, Vec<Key>>;
@@ -40,23 +40,28 @@ fn parse_line(input: String) -> crate::AoCResult<(Key, Vec<Key>)> {
     Ok((to_id(gate), outputs.split_whitespace().map(to_id).collect()))
 }
 
-cached_key! {
-    FIND_PATHS: UnboundCache<Key, usize> = UnboundCache::with_capacity(600);
-    Key = { id };
-    fn find_paths(id: Key, store: &Store) -> usize = {
+fn find_paths(memo: &mut Memo<Key, usize>, id: Key, store: &Store) -> usize {
+    memo.get_or_insert_with(id, |memo| {
         let my_outputs = &store[&id];
         log::debug!("Hello I'm {id} and my friends are {my_outputs:?}");
         my_outputs
             .iter()
             .copied()
-            .map(|out_id| if out_id == END { 1 } else { find_paths(out_id, store)})
+            .map(|out_id| {
+                if out_id == END {
+                    1
+                } else {
+                    find_paths(memo, out_id, store)
+                }
+            })
             .sum::<usize>()
-    }
+    })
 }
 
+#[solution(part = 1)]
 pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {
     let store: Store = data.map(parse_line).try_collect()?;
-    let ret = find_paths(YOU, &store);
+    let ret = find_paths(&mut Memo::with_capacity(600), YOU, &store);
     Ok(ret.to_string())
 }
 
@@ -76,14 +81,17 @@ impl PathState {
     }
 }
 
-cached_key! {
-    FIND_PATHS_2: UnboundCache<(Key, PathState), usize> = UnboundCache::with_capacity(600);
-    Key = { (id, path_state) };
-    fn find_paths_2(id: Key, path_state: PathState, store: &Store) -> usize = {
+fn find_paths_2(
+    memo: &mut Memo<(Key, PathState), usize>,
+    id: Key,
+    path_state: PathState,
+    store: &Store,
+) -> usize {
+    memo.get_or_insert_with((id, path_state), |memo| {
         let mut path_state = path_state;
         if id == FFT {
             path_state.found_fft = true;
-        } else if id == DAC{
+        } else if id == DAC {
             path_state.found_dac = true;
         }
         let my_outputs = &store[&id];
@@ -96,34 +104,33 @@ cached_key! {
                 if out_id == END {
                     path_state.get_score()
                 } else {
-                    find_paths_2(out_id, path_state, store)
+                    find_paths_2(memo, out_id, path_state, store)
                 }
             })
             .sum::<usize>()
-    }
+    })
 }
 
+#[solution(part = 2)]
 pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {
     let store: Store = data.map(parse_line).try_collect()?;
-    let ret = find_paths_2(SERVER, Default::default(), &store);
+    let ret = find_paths_2(
+        &mut Memo::with_capacity(600),
+        SERVER,
+        Default::default(),
+        &store,
+    );
     Ok(ret.to_string())
 }
 
-inventory::submit!(crate::AoCDay {
-    year: "2025",
-    day: "11",
-    part_1: crate::AoCPart {
-        main: part_1,
-        example: |data| {
-            let (data, _) = crate::partition_input(data);
-            part_1(data)
-        }
-    },
-    part_2: Some(crate::AoCPart {
-        main: part_2,
-        example: |data| {
-            let (_, data) = crate::partition_input(data);
-            part_2(data)
-        }
-    })
-});
+#[example(part = 1)]
+fn part_1_example(data: crate::DataIn) -> crate::AoCResult<String> {
+    let (data, _) = crate::partition_input(data);
+    part_1(data)
+}
+
+#[example(part = 2)]
+fn part_2_example(data: crate::DataIn) -> crate::AoCResult<String> {
+    let (_, data) = crate::partition_input(data);
+    part_2(data)
+}
@@ -10,6 +10,7 @@
 use std::fs::File;
 use std::io::prelude::*;
 
+use advent_of_code::utils::data::data_dir;
 use clap::Parser;
 use reqwest::StatusCode;
 use reqwest::blocking::Client;
@@ -68,18 +69,20 @@ fn main() -> Result<(), String> {
 
     let data = res.bytes().map_err(|e| e.to_string())?;
 
-    std::fs::create_dir_all(format!("./data/{year}/")).map_err(|e| e.to_string())?;
+    let year_dir = data_dir().join(year.to_string());
+    std::fs::create_dir_all(&year_dir).map_err(|e| e.to_string())?;
 
+    let data_path = year_dir.join(format!("{day:02}.txt"));
     let mut f = File::options()
         .create(true)
         .truncate(true)
         .write(true)
-        .open(format!("./data/{year}/{day:02}.txt"))
+        .open(&data_path)
         .map_err(|e| e.to_string())?;
 
     f.write_all(&data).map_err(|e| e.to_string())?;
 
-    println!("Written to data/{year}/{day:02}.txt!");
+    println!("Written to {}!", data_path.display());
 
     Ok(())
 }
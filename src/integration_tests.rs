@@ -15,15 +15,16 @@ use std::{collections::HashMap, time::Duration};
 use itertools::Itertools;
 use rstest::{fixture, rstest};
 
-use crate::{AoCData, AoCDay, AoCResult};
+use crate::runner::answers::{AnswerCache, Verdict};
+use crate::{Answer, AoCDay, AoCResult, DataSource, Part};
 
 type DayMap = HashMap<&'static str, HashMap<&'static str, &'static AoCDay>>;
 
 #[fixture]
 #[once]
 fn days() -> DayMap {
-    (inventory::iter::<AoCDay>)
-        .into_iter()
+    crate::all_days()
+        .iter()
         .map(|day_data| (day_data.year, (day_data.day, day_data)))
         .into_group_map()
         .into_iter()
@@ -31,24 +32,6 @@ fn days() -> DayMap {
         .collect()
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum Part {
-    Part1,
-    Part2,
-}
-
-const BAD_DAYS: [(&str, &str, Part); 9] = [
-    ("2015", "04", Part::Part1),
-    ("2015", "04", Part::Part2),
-    ("2019", "13", Part::Part2),
-    ("2019", "19", Part::Part1),
-    ("2019", "19", Part::Part2),
-    ("2021", "20", Part::Part2),
-    ("2021", "22", Part::Part1),
-    ("2022", "16", Part::Part1),
-    ("2023", "08", Part::Part2),
-];
-
 #[rstest]
 #[timeout(Duration::from_secs(3))]
 fn test_foo(
@@ -70,18 +53,22 @@ fn test_foo(
         .and_then(|year| year.strip_prefix("y"))
         .unwrap();
 
-    // inefficient but whatever
-    for bad_day in BAD_DAYS {
-        if bad_day == (year, day, part) {
-            return;
-        }
-    }
-
     let day = day.strip_prefix("0").unwrap_or(day);
+    let day_data = days[year][day];
 
-    let data = AoCData::new_from_file(year, day, true).unwrap();
+    // Set AOC_INCLUDE_SLOW to test the slow days as well
+    let include_slow = std::env::var_os("AOC_INCLUDE_SLOW").is_some();
+    if let Some(reason) = day_data
+        .get_part(part)
+        .and_then(|part_data| part_data.example_meta.skip_reason(include_slow))
+    {
+        println!("Skipping {year} day {day} part {part}: {reason}");
+        return;
+    }
 
-    let day_data = days[year][day];
+    let source = DataSource::find(year, day, true).unwrap();
+    let example = source.is_example();
+    let data = source.load().unwrap();
 
     let func = match part {
         Part::Part1 => Some(day_data.part_1.example),
@@ -91,12 +78,18 @@ fn test_foo(
     if let Some(func) = func
         && !std::ptr::fn_addr_eq(
             func,
-            crate::no_example as fn(crate::DataIn) -> AoCResult<String>,
+            crate::no_example as fn(crate::DataIn) -> AoCResult<Answer>,
         )
     {
-        let res = func(data.into_iter());
-        if let Err(err) = res {
-            panic!("{err}");
+        let res = match func(data.into_iter()) {
+            Ok(res) => res,
+            Err(err) => panic!("{err}"),
+        };
+        let verdict = AnswerCache::new()
+            .check(year, day, part, example, &res)
+            .unwrap();
+        if let Verdict::Incorrect { expected } = verdict {
+            panic!("expected {expected}, got {res}");
         }
     }
 }
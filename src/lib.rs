@@ -9,6 +9,7 @@
 
 pub mod aoc;
 pub mod utils {
+    pub mod answer;
     pub mod astar;
     pub mod bigcoord2d;
     pub mod bigcoord3d;
@@ -17,17 +18,46 @@ pub mod utils {
     pub mod coord2d;
     pub mod coord3d;
     pub mod data;
+    pub mod densegrid;
     pub mod direction;
     pub mod error;
+    pub mod examples;
     pub mod grid;
     pub mod gridstate;
     pub mod infgrid;
     pub mod input_partitioner;
+    pub mod memo;
+    pub mod params;
+    pub mod pathfinding;
+    pub mod pattern;
+    pub mod regions;
+    pub mod sections;
     pub mod sparsegrid;
+    pub mod tiledgrid;
+    pub mod tokens;
+    pub mod transform;
+    pub mod wrappinggrid;
 }
 mod integration_tests;
+pub mod runner {
+    pub mod answers;
+    pub mod batch;
+    pub mod bench;
+    pub mod output;
+    pub mod pool;
+    pub mod scaffold;
+}
 pub mod symbols;
 
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use itertools::Itertools;
+use lazy_static::lazy_static;
+
+use crate::utils::examples::{self, ExampleCase};
+
+pub use crate::utils::answer::Answer;
 pub use crate::utils::bigcoord2d::BigCoord2D;
 pub use crate::utils::bigcoord3d::BigCoord3D;
 pub use crate::utils::commongrid::CharGrid;
@@ -39,24 +69,140 @@ pub use crate::utils::coord::Coordinate2D;
 pub use crate::utils::coord2d::Coord2D;
 pub use crate::utils::coord3d::Coord3D;
 pub use crate::utils::data::AoCData;
+pub use crate::utils::data::DataSource;
+pub use crate::utils::densegrid::DenseGrid;
 pub use crate::utils::direction::Direction;
 pub use crate::utils::direction::RotateDirection;
 pub use crate::utils::error::AoCError;
 pub use crate::utils::error::AoCResult;
+pub use crate::utils::error::Context;
 pub use crate::utils::grid::Grid;
 pub use crate::utils::gridstate::GridState;
 pub use crate::utils::gridstate::VoidState;
 pub use crate::utils::infgrid::InfGrid;
 pub use crate::utils::input_partitioner::InputPartitioner;
+pub use crate::utils::memo::Memo;
+pub use crate::utils::pathfinding::DistanceMap;
+pub use crate::utils::regions::Components;
+pub use crate::utils::regions::Connectivity;
+pub use crate::utils::regions::Region;
 pub use crate::utils::sparsegrid::SparseGrid;
+pub use crate::utils::tiledgrid::TiledGrid;
+pub use crate::utils::transform::GridViews;
+pub use crate::utils::transform::ReadOnlyGrid;
+pub use crate::utils::transform::Symmetry;
+pub use crate::utils::transform::TransformGrid;
+pub use crate::utils::wrappinggrid::WrappingGrid;
 
 pub type DataIter<'a> = &'a mut dyn Iterator<Item = String>;
 pub type DataIn = utils::data::AoCDataIterator;
-pub type AoCDayFn = fn(DataIn) -> AoCResult<String>;
+pub type AoCDayFn = fn(DataIn) -> AoCResult<Answer>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    Part1,
+    Part2,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Part1 => "1",
+            Self::Part2 => "2",
+        }
+        .fmt(f)
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(value: Part) -> Self {
+        match value {
+            Part::Part1 => 1,
+            Part::Part2 => 2,
+        }
+    }
+}
+
+/// A value passed to a solution alongside the input, which can be overridden
+/// from the command line
+pub struct AoCParam {
+    pub name: &'static str,
+    pub default: fn() -> String,
+}
+
+/// Things to know about a part before running it
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AoCMeta {
+    /// Takes long enough that it shouldn't run unless asked for
+    pub slow: bool,
+    /// Wants someone to type things in while it runs
+    pub interactive: bool,
+    /// Draws on the terminal rather than just printing
+    pub needs_terminal: bool,
+    /// Doesn't give a useful answer without `--param`
+    pub requires_params: bool,
+    /// Doesn't work, and why
+    pub broken: Option<&'static str>,
+}
+
+impl AoCMeta {
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            slow: self.slow || other.slow,
+            interactive: self.interactive || other.interactive,
+            needs_terminal: self.needs_terminal || other.needs_terminal,
+            requires_params: self.requires_params || other.requires_params,
+            broken: self.broken.or(other.broken),
+        }
+    }
+
+    /// Why the part shouldn't be run unattended, if there's a reason
+    pub fn skip_reason(&self, include_slow: bool) -> Option<String> {
+        if let Some(reason) = self.broken {
+            Some(format!("known broken: {reason}"))
+        } else if self.interactive {
+            Some("interactive".to_owned())
+        } else if self.needs_terminal {
+            Some("needs a terminal".to_owned())
+        } else if self.requires_params {
+            Some("needs parameters".to_owned())
+        } else if self.slow && !include_slow {
+            Some("slow".to_owned())
+        } else {
+            None
+        }
+    }
+}
 
 pub struct AoCPart {
     pub main: AoCDayFn,
     pub example: AoCDayFn,
+    pub params: &'static [AoCParam],
+    pub example_params: &'static [AoCParam],
+    pub meta: AoCMeta,
+    pub example_meta: AoCMeta,
+}
+
+impl AoCPart {
+    pub fn get_fn(&self, example: bool) -> AoCDayFn {
+        if example { self.example } else { self.main }
+    }
+
+    pub fn get_params(&self, example: bool) -> &'static [AoCParam] {
+        if example {
+            self.example_params
+        } else {
+            self.params
+        }
+    }
+
+    pub fn get_meta(&self, example: bool) -> AoCMeta {
+        if example {
+            self.example_meta
+        } else {
+            self.meta
+        }
+    }
 }
 
 pub struct AoCDay {
@@ -66,24 +212,138 @@ pub struct AoCDay {
     pub part_2: Option<AoCPart>,
 }
 
-inventory::collect!(AoCDay);
+impl AoCDay {
+    pub fn get_part(&self, part: Part) -> Option<&AoCPart> {
+        match part {
+            Part::Part1 => Some(&self.part_1),
+            Part::Part2 => self.part_2.as_ref(),
+        }
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = (Part, &AoCPart)> {
+        [Part::Part1, Part::Part2]
+            .into_iter()
+            .filter_map(|part| self.get_part(part).map(|data| (part, data)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolutionKind {
+    Main,
+    Example,
+}
+
+/// A single function registered by `#[aoc_macros::solution]` or
+/// `#[aoc_macros::example]`
+pub struct AoCSolution {
+    pub module: &'static str,
+    pub part: Part,
+    pub kind: SolutionKind,
+    pub func: AoCDayFn,
+    pub params: &'static [AoCParam],
+    pub meta: AoCMeta,
+}
+
+inventory::collect!(AoCSolution);
+
+/// Works out the year and day from a module path like
+/// `advent_of_code::aoc::y2024::day01`
+fn parse_module_path(module: &'static str) -> (&'static str, &'static str) {
+    let mut segments = module.rsplit("::");
+    let day = segments
+        .next()
+        .and_then(|segment| segment.strip_prefix("day"))
+        .filter(|day| !day.is_empty() && day.bytes().all(|c| c.is_ascii_digit()));
+    let year = segments
+        .next()
+        .and_then(|segment| segment.strip_prefix('y'))
+        .filter(|year| !year.is_empty() && year.bytes().all(|c| c.is_ascii_digit()));
+    let (Some(year), Some(day)) = (year, day) else {
+        panic!("Solutions must be registered in a yYYYY::dayDD module, not {module}");
+    };
+    let day = day.trim_start_matches('0');
+    (year, if day.is_empty() { "0" } else { day })
+}
+
+fn collect_days() -> Vec<AoCDay> {
+    let mut modules: BTreeMap<&str, Vec<&AoCSolution>> = BTreeMap::new();
+    for solution in inventory::iter::<AoCSolution> {
+        modules.entry(solution.module).or_default().push(solution);
+    }
+
+    modules
+        .into_iter()
+        .map(|(module, solutions)| {
+            let get_solution = |part, kind| {
+                solutions
+                    .iter()
+                    .find(|solution| solution.part == part && solution.kind == kind)
+            };
+            let get_part = |part| {
+                get_solution(part, SolutionKind::Main).map(|main| {
+                    let example = get_solution(part, SolutionKind::Example).unwrap_or(main);
+                    AoCPart {
+                        main: main.func,
+                        example: example.func,
+                        params: main.params,
+                        example_params: example.params,
+                        meta: main.meta,
+                        example_meta: main.meta.merge(example.meta),
+                    }
+                })
+            };
+            let (year, day) = parse_module_path(module);
+            AoCDay {
+                year,
+                day,
+                part_1: get_part(Part::Part1).expect("The macros make sure part 1 exists"),
+                part_2: get_part(Part::Part2),
+            }
+        })
+        .sorted_by_key(|day| (day.year, day.day.parse::<u8>().unwrap_or_default()))
+        .collect()
+}
+
+lazy_static! {
+    static ref DAYS: Vec<AoCDay> = collect_days();
+}
+
+/// Every registered day, in year/day order
+pub fn all_days() -> &'static [AoCDay] {
+    &DAYS
+}
 
-pub fn multi_line_example(data: DataIn, main: AoCDayFn) -> AoCResult<String> {
+/// Runs every line of the example as a separate input. See
+/// [`utils::examples`] for how to say what each line should give.
+pub fn multi_line_example(data: DataIn, main: AoCDayFn, part: Part) -> AoCResult<Answer> {
+    let mut total = 0;
+    let mut failures = 0;
     for line in data {
-        println!("Example: {line}");
-        let res = main(AoCData::new_from_line(line).into_iter())?;
-        println!("Result: {res}\n===");
+        let (input, case) = ExampleCase::from_line(line, part)?;
+        println!("Example: {input}");
+        total += 1;
+        if !case.run(main)? {
+            failures += 1;
+        }
+        println!("===");
     }
-    Ok("".to_owned())
+    examples::check_failures(failures, total)
 }
 
-pub fn partitioned_example(data: DataIn, main: AoCDayFn) -> AoCResult<String> {
+/// Runs every blank line separated section of the example as a separate
+/// input. See [`utils::examples`] for how to say what each should give.
+pub fn partitioned_example(data: DataIn, main: AoCDayFn, part: Part) -> AoCResult<Answer> {
+    let mut total = 0;
+    let mut failures = 0;
     for (i, lines) in data.partition().enumerate() {
         println!("Example #{i}");
-        let res = main(lines.into_iter())?;
-        println!("Result: {res}\n===");
+        total += 1;
+        if !ExampleCase::from_lines(lines, part)?.run(main)? {
+            failures += 1;
+        }
+        println!("===");
     }
-    Ok("".to_owned())
+    examples::check_failures(failures, total)
 }
 
 pub fn partition_input(data: DataIn) -> (DataIn, DataIn) {
@@ -98,6 +358,6 @@ pub fn partition_input(data: DataIn) -> (DataIn, DataIn) {
     )
 }
 
-pub fn no_example(_: DataIn) -> AoCResult<String> {
+pub fn no_example(_: DataIn) -> AoCResult<Answer> {
     Err(AoCError::new("No example available"))
 }
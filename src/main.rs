@@ -7,100 +7,456 @@
 // <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
 // See the Licence for the specific language governing permissions and limitations under the Licence.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, IsTerminal};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::process::ExitCode;
-use std::time::Instant;
+use std::time::Duration;
 
 use env_logger::Builder;
 use itertools::Itertools;
 use log::LevelFilter;
 
-use advent_of_code::{AoCData, AoCDay, AoCError, AoCResult};
+use advent_of_code::runner::answers::{AnswerCache, Verdict};
+use advent_of_code::runner::batch::{self, RunOptions, RunStatus};
+use advent_of_code::runner::output::{self, OutputFormat};
+use advent_of_code::runner::pool::{self, Job};
+use advent_of_code::runner::{bench, scaffold};
+use advent_of_code::utils::data::set_data_dir;
+use advent_of_code::utils::params;
+use advent_of_code::{Answer, AoCDay, AoCError, AoCMeta, AoCParam, AoCResult, DataSource, Part};
 
 type DayMap = HashMap<&'static str, HashMap<&'static str, &'static AoCDay>>;
 
-fn main_wrapped() -> AoCResult<()> {
-    let all_days: DayMap = (inventory::iter::<AoCDay>)
+fn find_source(day_data: &AoCDay, options: &RunOptions) -> AoCResult<DataSource> {
+    let year = day_data.year;
+    let day = day_data.day;
+    match &options.input {
+        Some(input) => Ok(input.clone()),
+        None => DataSource::find(year, day, options.use_example)
+            .ok_or_else(|| AoCError::new(format!("No data found for {year} day {day:0>2}!"))),
+    }
+}
+
+/// Makes sure every parameter given on the command line is used by at least
+/// one of the days that are about to run
+fn check_params<'a, I>(days: I, options: &RunOptions) -> AoCResult<()>
+where
+    I: IntoIterator<Item = &'a AoCDay>,
+{
+    let known: HashSet<&str> = days
         .into_iter()
+        .flat_map(|day_data| day_data.parts())
+        .filter(|(part, _)| options.parts.contains(part))
+        .flat_map(|(_, part_data)| part_data.params.iter().chain(part_data.example_params))
+        .map(|param| param.name)
+        .collect();
+    match params::overridden_names().find(|name| !known.contains(name)) {
+        Some(name) => Err(AoCError::new(format!(
+            "Nothing being run has a parameter called {name}"
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Refuses to run parts that can't work the way they're being run, and warns
+/// about any that might not
+fn check_runnable(
+    day_data: &AoCDay,
+    part: Part,
+    meta: AoCMeta,
+    params: &[AoCParam],
+) -> AoCResult<()> {
+    let name = format!("{} day {} part {part}", day_data.year, day_data.day);
+    if let Some(reason) = meta.broken {
+        log::warn!("{name} is known to be broken: {reason}");
+    }
+    if meta.interactive && !io::stdin().is_terminal() {
+        return Err(AoCError::new(format!(
+            "{name} is interactive and must be run from a terminal"
+        )));
+    } else if meta.needs_terminal && !io::stdout().is_terminal() {
+        return Err(AoCError::new(format!(
+            "{name} draws on the terminal and can't have its output redirected"
+        )));
+    } else if meta.requires_params {
+        let overridden: HashSet<&str> = params::overridden_names().collect();
+        let missing = params
+            .iter()
+            .map(|param| param.name)
+            .filter(|param| !overridden.contains(param))
+            .collect_vec();
+        if params.is_empty() {
+            return Err(AoCError::new(format!(
+                "{name} needs parameters but doesn't declare any"
+            )));
+        } else if !missing.is_empty() {
+            return Err(AoCError::new(format!(
+                "{name} needs to be given {} with --param",
+                missing.join(", ")
+            )));
+        }
+    }
+    if meta.slow {
+        log::info!("{name} is slow, this may take a while");
+    }
+    Ok(())
+}
+
+fn run_single(day_data: &'static AoCDay, options: &RunOptions) -> AoCResult<()> {
+    let year = day_data.year;
+    let day = day_data.day;
+    let parts = &options.parts;
+
+    // sanity checking
+    if *parts == [Part::Part2] && day_data.part_2.is_none() {
+        return Err(AoCError::new(format!(
+            "{year}/{day} does not have a part 2 defined!"
+        )));
+    }
+
+    check_params([day_data], options)?;
+    let source = find_source(day_data, options)?;
+    let data = source.load()?;
+    let use_example = options.uses_example(&source);
+
+    let mut answers = AnswerCache::new();
+    let mut incorrect = 0;
+
+    for (part, part_data) in day_data.parts().filter(|(part, _)| parts.contains(part)) {
+        check_runnable(
+            day_data,
+            part,
+            part_data.get_meta(use_example),
+            part_data.get_params(use_example),
+        )?;
+        println!("=== {year} day {day} part {part} ===");
+        let params = part_data.get_params(use_example);
+        if !params.is_empty() {
+            println!("=== Parameters: {} ===", params::describe(params));
+        }
+
+        let job = Job {
+            day: day_data,
+            part,
+            data: data.clone(),
+            example: use_example,
+        };
+        let result = pool::run_jobs(vec![job], NonZeroUsize::MIN, options.timeout)
+            .pop()
+            .expect("Every job must have a result");
+        let ret = match (result.status, result.answer) {
+            (RunStatus::Ok, Some(answer)) => answer,
+            (RunStatus::Error(message), _) => return Err(AoCError::new(message)),
+            (RunStatus::TimedOut, _) => {
+                return Err(AoCError::new(format!(
+                    "{year} day {day} part {part} timed out"
+                )));
+            }
+            (status, _) => return Err(AoCError::new(status.to_string())),
+        };
+
+        if let Some(duration) = result.duration {
+            println!("=== Result in {} ===", humantime::format_duration(duration));
+        }
+        match ret {
+            Answer::NoAnswer => println!("=== No answer, check the output above ==="),
+            _ => println!("{ret}"),
+        }
+
+        if options.check {
+            let verdict = answers.check(year, day, part, use_example, &ret)?;
+            println!("=== Check: {verdict} ===");
+            if matches!(verdict, Verdict::Incorrect { .. }) {
+                incorrect += 1;
+            }
+        }
+    }
+
+    if incorrect > 0 {
+        return Err(AoCError::new(format!("{incorrect} incorrect answers")));
+    }
+    Ok(())
+}
+
+fn run_batch<I>(days: I, options: &RunOptions) -> AoCResult<()>
+where
+    I: IntoIterator<Item = &'static AoCDay>,
+{
+    let days = days.into_iter().collect_vec();
+    check_params(days.iter().copied(), options)?;
+
+    let mut real_stdout = match options.output {
+        OutputFormat::Text => None,
+        _ => Some(output::redirect_stdout()?),
+    };
+
+    let mut results = batch::run_days(days, options);
+    if options.check {
+        batch::check_results(&mut results, &mut AnswerCache::new())?;
+    }
+
+    match real_stdout.as_mut() {
+        Some(out) => output::write_records(out, options.output, &results)?,
+        None => batch::print_table(&results),
+    }
+
+    let failures = results
+        .iter()
+        .filter(|result| result.status.is_failure())
+        .count();
+    if failures > 0 {
+        return Err(AoCError::new(format!("{failures} parts failed")));
+    }
+    Ok(())
+}
+
+fn run_bench(
+    day_data: &'static AoCDay,
+    options: &RunOptions,
+    args: &clap::ArgMatches,
+) -> AoCResult<()> {
+    let year = day_data.year;
+    let day = day_data.day;
+    let iterations = *args.get_one::<u64>("iterations").unwrap() as usize;
+    let warmup = *args.get_one::<usize>("warmup").unwrap();
+    let threshold = *args.get_one::<f64>("threshold").unwrap();
+
+    check_params([day_data], options)?;
+    let source = find_source(day_data, options)?;
+    let example_data = options.uses_example(&source);
+    let input = source.read()?;
+    let baseline = args
+        .get_one::<PathBuf>("baseline")
+        .map(|path| bench::load_baseline(path))
+        .transpose()?;
+
+    let mut results = bench::Baseline::new();
+    let mut regressions = 0;
+    for (part, part_data) in day_data
+        .parts()
+        .filter(|(part, _)| options.parts.contains(part))
+    {
+        let meta = part_data.get_meta(example_data);
+        if meta.interactive {
+            return Err(AoCError::new(format!(
+                "{year} day {day} part {part} is interactive and can't be benchmarked"
+            )));
+        }
+        check_runnable(day_data, part, meta, part_data.get_params(example_data))?;
+        println!("=== {year} day {day} part {part} ({iterations} runs) ===");
+        let result = bench::run_bench(
+            day_data,
+            part,
+            &input,
+            example_data,
+            warmup,
+            iterations,
+            options.timeout,
+        )?;
+        println!("load: {}", result.load);
+        println!("solve: {}", result.solve);
+
+        let key = bench::bench_key(year, day, part);
+        if let Some(old) = baseline.as_ref().and_then(|baseline| baseline.get(&key)) {
+            let change = bench::median_change(&old.solve, &result.solve);
+            if change > threshold {
+                regressions += 1;
+                println!("=== {change:+.1}% vs baseline: REGRESSION ===");
+            } else {
+                println!("=== {change:+.1}% vs baseline ===");
+            }
+        }
+        results.insert(key, result);
+    }
+
+    if let Some(path) = args.get_one::<PathBuf>("save") {
+        bench::save_baseline(path, &results)?;
+    }
+
+    if regressions > 0 {
+        return Err(AoCError::new(format!(
+            "{regressions} parts regressed by more than {threshold}%"
+        )));
+    }
+    Ok(())
+}
+
+fn main_wrapped() -> AoCResult<()> {
+    let all_days: DayMap = advent_of_code::all_days()
+        .iter()
         .map(|day_data| (day_data.year, (day_data.day, day_data)))
         .into_group_map()
         .into_iter()
         .map(|(key, days)| (key, days.into_iter().collect()))
         .collect();
 
-    let mut options = clap::command!()
+    let mut command = clap::command!()
         .arg(clap::arg!(--example "Read the example data file instead"))
+        .arg(clap::arg!(--check "Compare the results against the known answers"))
+        .arg(clap::arg!(--"include-slow" "Run slow parts too when running multiple days"))
+        .arg(clap::arg!(--input <FILE> "Read this file instead of the data directory, or - for stdin"))
+        .arg(
+            clap::arg!(--"data-dir" <DIR> "Where to find the puzzle inputs")
+                .env("AOC_DATA_DIR")
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            clap::Arg::new("output")
+                .long("output")
+                .help("How to format the results")
+                .default_value("text")
+                .value_parser(["text", "json", "ndjson"]),
+        )
         .arg(
             clap::Arg::new("part")
                 .long("part")
                 .default_value("both")
                 .value_parser(["1", "2", "both"]),
         )
-        .subcommand_required(true);
+        .arg(
+            clap::arg!(--param <"NAME=VALUE"> "Override one of a day's parameters")
+                .action(clap::ArgAction::Append)
+                .value_parser(params::parse_override),
+        )
+        .arg(
+            clap::arg!(--jobs <COUNT> "How many parts to run at once when running multiple days")
+                .value_parser(clap::value_parser!(NonZeroUsize)),
+        )
+        .arg(
+            clap::arg!(--timeout <DURATION> "Give up on any part that takes longer than this")
+                .value_parser(humantime::parse_duration),
+        )
+        .subcommand_required(true)
+        .subcommand(clap::Command::new("all").about("Run every registered day"))
+        .subcommand(
+            clap::Command::new("new")
+                .about("Create the boilerplate for a new day")
+                .arg(
+                    clap::Arg::new("year")
+                        .required(true)
+                        .value_parser(clap::value_parser!(u16)),
+                )
+                .arg(
+                    clap::Arg::new("day")
+                        .required(true)
+                        .value_parser(clap::value_parser!(u8)),
+                ),
+        )
+        .subcommand(
+            clap::Command::new("bench")
+                .about("Time a day over many runs")
+                .arg(
+                    clap::Arg::new("year")
+                        .required(true)
+                        .value_parser(all_days.keys().copied().collect_vec()),
+                )
+                .arg(clap::Arg::new("day").required(true))
+                .arg(
+                    clap::arg!(--iterations <COUNT> "How many timed runs to do")
+                        .value_parser(clap::value_parser!(u64).range(1..))
+                        .default_value("100"),
+                )
+                .arg(
+                    clap::arg!(--warmup <COUNT> "How many untimed runs to do first")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("10"),
+                )
+                .arg(
+                    clap::arg!(--baseline <FILE> "Compare the results against a saved baseline")
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    clap::arg!(--save <FILE> "Save the results to a baseline file")
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    clap::arg!(--threshold <PERCENT> "How much slower counts as a regression")
+                        .value_parser(clap::value_parser!(f64))
+                        .default_value("10"),
+                ),
+        );
     for (year, days) in all_days.iter() {
-        options = options.subcommand(
+        command = command.subcommand(
             clap::Command::new(year).arg(
                 clap::Arg::new("day")
                     .required(true)
-                    .value_parser(days.keys().collect_vec()),
+                    .help("The day to run, or \"all\" to run every day in the year")
+                    .value_parser(days.keys().copied().chain(["all"]).collect_vec()),
             ),
         );
     }
-    let matches = options.get_matches();
-    let (year, year_args) = matches.subcommand().unwrap();
-    let day = year_args.get_one::<String>("day").unwrap().as_str();
-    let use_example = matches.get_flag("example");
-    let part = matches.get_one::<String>("part").unwrap().as_str();
-
-    let data = AoCData::new_from_file(year, day, use_example)?;
-
-    let day_data = all_days[year][day];
-
-    // sanity checking
-    if part == "2" && day_data.part_2.is_none() {
-        return Err(AoCError::new(format!(
-            "{year}/{day} does not have a part 2 defined!"
-        )));
+    let matches = command.get_matches();
+    if let Some(data_dir) = matches.get_one::<PathBuf>("data-dir") {
+        set_data_dir(data_dir.clone())?;
     }
-
-    if part == "1" || part == "both" {
-        println!("=== {year} day {day} part 1 ===");
-
-        let func = {
-            let part = &day_data.part_1;
-            if use_example { part.example } else { part.main }
-        };
-
-        let start = Instant::now();
-        let ret = func(data.clone().into_iter())?;
-        let end = Instant::now();
-
-        println!(
-            "=== Result in {} ===",
-            humantime::format_duration(end.duration_since(start))
-        );
-        println!("{}", ret);
+    if let Some(overrides) = matches.get_many::<(String, String)>("param") {
+        params::set_overrides(overrides.cloned().collect())?;
     }
-    if part == "2" || (part == "both" && day_data.part_2.is_some()) {
-        println!("=== {year} day {day} part 2 ===");
-
-        let func = day_data
-            .part_2
-            .as_ref()
-            .map(|part| if use_example { part.example } else { part.main })
-            .unwrap();
+    let options = RunOptions {
+        parts: match matches.get_one::<String>("part").unwrap().as_str() {
+            "1" => vec![Part::Part1],
+            "2" => vec![Part::Part2],
+            _ => vec![Part::Part1, Part::Part2],
+        },
+        use_example: matches.get_flag("example"),
+        input: matches
+            .get_one::<String>("input")
+            .map(|input| DataSource::new_from_arg(input)),
+        check: matches.get_flag("check"),
+        output: matches.get_one::<String>("output").unwrap().parse()?,
+        jobs: matches
+            .get_one::<NonZeroUsize>("jobs")
+            .copied()
+            .or_else(|| std::thread::available_parallelism().ok())
+            .unwrap_or(NonZeroUsize::MIN),
+        timeout: matches.get_one::<Duration>("timeout").copied(),
+        include_slow: matches.get_flag("include-slow"),
+    };
 
-        let start = Instant::now();
-        let ret = func(data.into_iter())?;
-        let end = Instant::now();
+    if options.check && options.input.is_some() {
+        return Err(AoCError::new(
+            "There are no known answers for custom inputs",
+        ));
+    }
+    let multiple_days = match matches.subcommand().unwrap() {
+        ("all", _) => true,
+        ("bench" | "new", _) => false,
+        (_, year_args) => year_args.get_one::<String>("day").unwrap() == "all",
+    };
+    if multiple_days && options.input.is_some() {
+        return Err(AoCError::new(
+            "Custom inputs only work when running a single day",
+        ));
+    }
 
-        println!(
-            "=== Result in {} ===",
-            humantime::format_duration(end.duration_since(start))
-        );
-        println!("{}", ret);
+    match matches.subcommand().unwrap() {
+        ("all", _) => run_batch(advent_of_code::all_days(), &options),
+        ("new", new_args) => {
+            let year = *new_args.get_one::<u16>("year").unwrap();
+            let day = *new_args.get_one::<u8>("day").unwrap();
+            for path in scaffold::new_day(year, day)? {
+                println!("Wrote {}", path.display());
+            }
+            Ok(())
+        }
+        ("bench", bench_args) => {
+            let year = bench_args.get_one::<String>("year").unwrap().as_str();
+            let day = bench_args.get_one::<String>("day").unwrap().as_str();
+            let day_data = all_days[year].get(day).ok_or_else(|| {
+                AoCError::new(format!("{year} does not have a day {day} defined!"))
+            })?;
+            run_bench(day_data, &options, bench_args)
+        }
+        (year, year_args) => match year_args.get_one::<String>("day").unwrap().as_str() {
+            "all" => run_batch(all_days[year].values().copied(), &options),
+            day if options.output != OutputFormat::Text => {
+                run_batch([all_days[year][day]], &options)
+            }
+            day => run_single(all_days[year][day], &options),
+        },
     }
-    Ok(())
 }
 
 fn main() -> ExitCode {
@@ -113,7 +469,7 @@ fn main() -> ExitCode {
     match main_wrapped() {
         Ok(_) => ExitCode::SUCCESS,
         Err(err) => {
-            eprintln!("Error: {err}");
+            eprintln!("Error: {err:#}");
             ExitCode::FAILURE
         }
     }
@@ -0,0 +1,181 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::utils::data::data_dir;
+use crate::{Answer, AoCError, AoCResult, Part};
+
+/// Lets people write numeric answers as numbers rather than having to quote
+/// them all
+fn deserialize_answer<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawAnswer {
+        Text(String),
+        Signed(i64),
+        Unsigned(u64),
+    }
+
+    Ok(
+        Option::<RawAnswer>::deserialize(deserializer)?.map(|answer| match answer {
+            RawAnswer::Text(text) => text,
+            RawAnswer::Signed(num) => num.to_string(),
+            RawAnswer::Unsigned(num) => num.to_string(),
+        }),
+    )
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayAnswers {
+    #[serde(default, deserialize_with = "deserialize_answer")]
+    pub part_1: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_answer")]
+    pub part_2: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_answer")]
+    pub example_1: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_answer")]
+    pub example_2: Option<String>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, part: Part, example: bool) -> Option<&str> {
+        match (part, example) {
+            (Part::Part1, false) => self.part_1.as_deref(),
+            (Part::Part2, false) => self.part_2.as_deref(),
+            (Part::Part1, true) => self.example_1.as_deref(),
+            (Part::Part2, true) => self.example_2.as_deref(),
+        }
+    }
+}
+
+/// Known-correct answers, stored per year in `data/{year}/answers.json` like so:
+/// `{ "01": { "part_1": 1234, "part_2": "abc", "example_1": 11, "example_2": 31 } }`
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<String, DayAnswers>);
+
+impl Answers {
+    pub fn get_path(year: &str) -> PathBuf {
+        data_dir().join(year).join("answers.json")
+    }
+
+    /// Loads the answers for a year, if anyone's bothered to write them down
+    pub fn load(year: &str) -> AoCResult<Option<Self>> {
+        let path = Self::get_path(year);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let data = fs::read_to_string(&path).map_err(|cause| {
+            AoCError::new_with_cause(format!("Failed to read {}", path.to_string_lossy()), cause)
+        })?;
+        let answers: HashMap<String, DayAnswers> =
+            serde_json::from_str(&data).map_err(|cause| {
+                AoCError::new_with_cause(
+                    format!("Failed to parse {}", path.to_string_lossy()),
+                    cause,
+                )
+            })?;
+        // Normalise the days so "1" and "01" are the same thing
+        Ok(Some(Self(
+            answers
+                .into_iter()
+                .map(|(day, answers)| (format!("{:0>2}", day), answers))
+                .collect(),
+        )))
+    }
+
+    pub fn get(&self, day: &str, part: Part, example: bool) -> Option<&str> {
+        self.0
+            .get(&format!("{:0>2}", day))
+            .and_then(|answers| answers.get(part, example))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+    Unknown,
+    /// The solution didn't return anything that can be checked
+    Unchecked {
+        expected: String,
+    },
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&str>, actual: &Answer) -> Self {
+        let Some(expected) = expected else {
+            return Self::Unknown;
+        };
+        let expected = expected.to_owned();
+        match actual.matches(&expected) {
+            Some(true) => Self::Correct,
+            Some(false) => Self::Incorrect { expected },
+            None => Self::Unchecked { expected },
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Incorrect { expected } => write!(f, "incorrect, expected {expected}"),
+            Self::Unknown => write!(f, "no known answer"),
+            Self::Unchecked { expected } => write!(f, "check by eye, expected {expected}"),
+        }
+    }
+}
+
+/// Lazily loads each year's answers as they're needed
+#[derive(Debug, Default)]
+pub struct AnswerCache(HashMap<String, Option<Answers>>);
+
+impl AnswerCache {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn get(
+        &mut self,
+        year: &str,
+        day: &str,
+        part: Part,
+        example: bool,
+    ) -> AoCResult<Option<&str>> {
+        if !self.0.contains_key(year) {
+            self.0.insert(year.to_owned(), Answers::load(year)?);
+        }
+        Ok(self.0[year]
+            .as_ref()
+            .and_then(|answers| answers.get(day, part, example)))
+    }
+
+    pub fn check(
+        &mut self,
+        year: &str,
+        day: &str,
+        part: Part,
+        example: bool,
+        actual: &Answer,
+    ) -> AoCResult<Verdict> {
+        Ok(Verdict::new(self.get(year, day, part, example)?, actual))
+    }
+}
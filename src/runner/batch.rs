@@ -0,0 +1,306 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::fmt::Display;
+use std::num::NonZeroUsize;
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+
+use super::answers::{AnswerCache, Verdict};
+use super::pool::{self, Job};
+use crate::{Answer, AoCData, AoCDay, AoCResult, DataSource, Part};
+
+use super::output::OutputFormat;
+
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    pub parts: Vec<Part>,
+    pub use_example: bool,
+    /// Use this instead of looking in the data directory
+    pub input: Option<DataSource>,
+    pub check: bool,
+    pub output: OutputFormat,
+    pub jobs: NonZeroUsize,
+    pub timeout: Option<Duration>,
+    /// Run parts that are marked as slow when running multiple days
+    pub include_slow: bool,
+}
+
+impl RunOptions {
+    /// Whether the example functions and parameters should be used for this
+    /// data, since `DataSource::find` falls back to the real input when
+    /// there's no example
+    pub fn uses_example(&self, source: &DataSource) -> bool {
+        match source {
+            DataSource::DataFile { example, .. } => *example,
+            _ => self.use_example,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunStatus {
+    Ok,
+    Error(String),
+    Incorrect { expected: String },
+    Panicked(String),
+    TimedOut,
+    MissingData,
+    Skipped(String),
+}
+
+impl RunStatus {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::Error(_) | Self::Incorrect { .. } | Self::Panicked(_) | Self::TimedOut
+        )
+    }
+}
+
+impl Display for RunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ok => write!(f, "ok"),
+            Self::Error(message) => write!(f, "error: {message}"),
+            Self::Incorrect { expected } => write!(f, "incorrect, expected {expected}"),
+            Self::Panicked(message) => write!(f, "panicked: {message}"),
+            Self::TimedOut => write!(f, "timed out"),
+            Self::MissingData => write!(f, "missing data"),
+            Self::Skipped(reason) => write!(f, "skipped: {reason}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RunResult {
+    pub year: &'static str,
+    pub day: &'static str,
+    pub part: Part,
+    pub example: bool,
+    pub answer: Option<Answer>,
+    pub duration: Option<Duration>,
+    pub status: RunStatus,
+}
+
+impl RunResult {
+    pub fn new(day: &AoCDay, part: Part, example: bool, status: RunStatus) -> Self {
+        Self {
+            year: day.year,
+            day: day.day,
+            part,
+            example,
+            answer: None,
+            duration: None,
+            status,
+        }
+    }
+}
+
+fn day_sort_key(day: &AoCDay) -> (&'static str, u32) {
+    (day.year, day.day.parse().unwrap_or(u32::MAX))
+}
+
+/// Sorts days into year/day order, since they've usually been through a
+/// HashMap by the time they get here
+pub fn sort_days<'a, I>(days: I) -> Vec<&'a AoCDay>
+where
+    I: IntoIterator<Item = &'a AoCDay>,
+{
+    days.into_iter()
+        .sorted_by_key(|day| day_sort_key(day))
+        .collect()
+}
+
+pub fn run_part(day: &AoCDay, part: Part, data: AoCData, example: bool) -> RunResult {
+    let Some(func) = day.get_part(part).map(|part| part.get_fn(example)) else {
+        return RunResult::new(
+            day,
+            part,
+            example,
+            RunStatus::Error(format!("part {part} is not defined")),
+        );
+    };
+
+    let start = Instant::now();
+    let ret = func(data.into_iter());
+    let end = Instant::now();
+
+    let mut result = RunResult::new(day, part, example, RunStatus::Ok);
+    result.duration = Some(end.duration_since(start));
+    match ret {
+        Ok(answer) => result.answer = Some(answer),
+        // AoCError can't be sent between threads, so keep the causes on their
+        // own lines while it's still around
+        Err(err) => result.status = RunStatus::Error(format!("{err:#}")),
+    }
+    result
+}
+
+/// Finds the data for a day, returning whether it's the example along with it
+fn find_data(day: &AoCDay, options: &RunOptions) -> Result<(DataSource, bool), RunStatus> {
+    let source = match &options.input {
+        Some(input) => input.clone(),
+        None => DataSource::find(day.year, day.day, options.use_example)
+            .ok_or(RunStatus::MissingData)?,
+    };
+    let example = options.uses_example(&source);
+    Ok((source, example))
+}
+
+/// Runs every requested part of every day, carrying on regardless of any
+/// failures along the way
+pub fn run_days<I>(days: I, options: &RunOptions) -> Vec<RunResult>
+where
+    I: IntoIterator<Item = &'static AoCDay>,
+{
+    // Anything that can't be run gets its result immediately, the rest get a
+    // placeholder until the pool's done with them
+    let mut results: Vec<Option<RunResult>> = Vec::new();
+    let mut jobs = Vec::new();
+    for day in sort_days(days) {
+        let found = find_data(day, options);
+        let example = found
+            .as_ref()
+            .map_or(options.use_example, |(_, example)| *example);
+        // Only load the data if there's something to run
+        let mut loaded = None;
+        for (part, part_data) in day.parts().filter(|(part, _)| options.parts.contains(part)) {
+            let meta = part_data.get_meta(example);
+            if let Some(reason) = meta.skip_reason(options.include_slow) {
+                results.push(Some(RunResult::new(
+                    day,
+                    part,
+                    example,
+                    RunStatus::Skipped(reason),
+                )));
+                continue;
+            }
+            let data = loaded.get_or_insert_with(|| {
+                let (source, _) = found.clone()?;
+                source
+                    .load()
+                    .map_err(|err| RunStatus::Error(format!("{err:#}")))
+            });
+            match data {
+                Ok(data) => {
+                    results.push(None);
+                    jobs.push(Job {
+                        day,
+                        part,
+                        data: data.clone(),
+                        example,
+                    });
+                }
+                Err(status) => {
+                    results.push(Some(RunResult::new(day, part, example, status.clone())))
+                }
+            }
+        }
+    }
+
+    let mut finished = pool::run_jobs(jobs, options.jobs, options.timeout).into_iter();
+    results
+        .into_iter()
+        .map(|result| {
+            result
+                .or_else(|| finished.next())
+                .expect("Every job must have a result")
+        })
+        .collect()
+}
+
+/// Compares any successful results against the known answers
+pub fn check_results(results: &mut [RunResult], answers: &mut AnswerCache) -> AoCResult<()> {
+    for result in results.iter_mut() {
+        let Some(answer) = result.answer.as_ref() else {
+            continue;
+        };
+        if result.status != RunStatus::Ok {
+            continue;
+        }
+        let verdict =
+            answers.check(result.year, result.day, result.part, result.example, answer)?;
+        if let Verdict::Incorrect { expected } = verdict {
+            result.status = RunStatus::Incorrect { expected };
+        }
+    }
+    Ok(())
+}
+
+pub fn print_table(results: &[RunResult]) {
+    const HEADERS: [&str; 6] = ["Year", "Day", "Part", "Answer", "Time", "Status"];
+
+    let rows = results
+        .iter()
+        .map(|result| {
+            [
+                result.year.to_owned(),
+                format!("{:0>2}", result.day),
+                result.part.to_string(),
+                result
+                    .answer
+                    .as_ref()
+                    .map(Answer::summary)
+                    .unwrap_or_default(),
+                result
+                    .duration
+                    .map(|duration| format!("{duration:.2?}"))
+                    .unwrap_or_default(),
+                // Put any causes back on one line so they fit in the table
+                result.status.to_string().replace("\n  caused by: ", ": "),
+            ]
+        })
+        .collect_vec();
+
+    let mut widths = HEADERS.map(|header| header.chars().count());
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |row: &[&str]| {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{cell: <width$}"))
+            .join(" │ ");
+        println!("{}", line.trim_end());
+    };
+
+    print_row(&HEADERS);
+    println!(
+        "{}",
+        widths.iter().map(|width| "─".repeat(*width)).join("─┼─")
+    );
+    for row in rows.iter() {
+        print_row(&row.each_ref().map(|cell| cell.as_str()));
+    }
+
+    let ok = count_where(results, |status| *status == RunStatus::Ok);
+    let incorrect = count_where(results, |status| {
+        matches!(status, RunStatus::Incorrect { .. })
+    });
+    let failed = count_where(results, RunStatus::is_failure) - incorrect;
+    let missing = count_where(results, |status| *status == RunStatus::MissingData);
+    let skipped = count_where(results, |status| matches!(status, RunStatus::Skipped(_)));
+    println!(
+        "\n{} parts: {ok} ok, {failed} failed, {incorrect} incorrect, {missing} missing data, {skipped} skipped",
+        results.len(),
+    );
+}
+
+fn count_where<P>(results: &[RunResult], pred: P) -> usize
+where
+    P: Fn(&RunStatus) -> bool,
+{
+    results.iter().filter(|result| pred(&result.status)).count()
+}
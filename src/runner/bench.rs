@@ -0,0 +1,229 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use super::batch::RunStatus;
+use super::pool::{self, Job};
+use crate::{AoCData, AoCDay, AoCError, AoCResult, Part};
+
+/// All timings are in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Can't do stats on nothing");
+        let samples = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .sorted_by(f64::total_cmp)
+            .collect_vec();
+        let count = samples.len();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+        let median = if count % 2 == 0 {
+            (samples[count / 2 - 1] + samples[count / 2]) / 2.0
+        } else {
+            samples[count / 2]
+        };
+        Self {
+            samples: count,
+            min: samples[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos as u64))
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {} median {} mean {} ± {}",
+            format_nanos(self.min),
+            format_nanos(self.median),
+            format_nanos(self.mean),
+            format_nanos(self.stddev),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BenchResult {
+    /// Splitting the input into lines. Each day does its own parsing, so
+    /// that's counted as part of solving
+    #[serde(alias = "parse")]
+    pub load: Stats,
+    /// Actually running the solution
+    pub solve: Stats,
+}
+
+pub type Baseline = BTreeMap<String, BenchResult>;
+
+pub fn bench_key(year: &str, day: &str, part: Part) -> String {
+    format!("{year}/{day:0>2}/{part}")
+}
+
+/// Each run goes through the pool so a panicking part or one that blows
+/// through the timeout fails the bench rather than taking the process with it
+pub fn run_bench(
+    day: &'static AoCDay,
+    part: Part,
+    input: &str,
+    example: bool,
+    warmup: usize,
+    iterations: usize,
+    timeout: Option<Duration>,
+) -> AoCResult<BenchResult> {
+    if day.get_part(part).is_none() {
+        return Err(AoCError::new(format!("Part {part} is not defined")));
+    }
+
+    let mut load_times = Vec::with_capacity(iterations);
+    let mut solve_times = Vec::with_capacity(iterations);
+    for i in 0..(warmup + iterations) {
+        let input = input.to_owned();
+        let start = Instant::now();
+        let data = AoCData::new_from_data(input);
+        let loaded = start.elapsed();
+
+        let job = Job {
+            day,
+            part,
+            data,
+            example,
+        };
+        let result = pool::run_jobs(vec![job], NonZeroUsize::MIN, timeout)
+            .pop()
+            .expect("Every job must have a result");
+        let solved = match (result.status, result.duration) {
+            (RunStatus::Ok, Some(duration)) => duration,
+            (RunStatus::Error(message), _) => return Err(AoCError::new(message)),
+            (status, _) => return Err(AoCError::new(status.to_string())),
+        };
+
+        if i >= warmup {
+            load_times.push(loaded);
+            solve_times.push(solved);
+        }
+    }
+
+    Ok(BenchResult {
+        load: Stats::new(&load_times),
+        solve: Stats::new(&solve_times),
+    })
+}
+
+pub fn load_baseline(path: &Path) -> AoCResult<Baseline> {
+    let data = fs::read_to_string(path).map_err(|cause| {
+        AoCError::new_with_cause(format!("Failed to read {}", path.to_string_lossy()), cause)
+    })?;
+    serde_json::from_str(&data).map_err(|cause| {
+        AoCError::new_with_cause(format!("Failed to parse {}", path.to_string_lossy()), cause)
+    })
+}
+
+/// Adds the results to the baseline file, replacing any previous results for
+/// the same parts but leaving the rest alone
+pub fn save_baseline(path: &Path, results: &Baseline) -> AoCResult<()> {
+    let mut baseline = if path.exists() {
+        load_baseline(path)?
+    } else {
+        Baseline::new()
+    };
+    baseline.extend(results.iter().map(|(key, result)| (key.clone(), *result)));
+    let data = serde_json::to_string_pretty(&baseline)
+        .map_err(|cause| AoCError::new_with_cause("Failed to serialise baseline", cause))?;
+    fs::write(path, data).map_err(|cause| {
+        AoCError::new_with_cause(format!("Failed to write {}", path.to_string_lossy()), cause)
+    })
+}
+
+/// How much slower (positive) or faster (negative) the new median is, as a
+/// percentage of the old one. Anything at all is infinitely slower than a
+/// baseline of zero, so that still gets flagged.
+pub fn median_change(old: &Stats, new: &Stats) -> f64 {
+    if old.median == 0.0 {
+        if new.median == 0.0 {
+            return 0.0;
+        }
+        return f64::INFINITY;
+    }
+    (new.median - old.median) / old.median * 100.0
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Stats, median_change};
+
+    fn stats(nanos: &[u64]) -> Stats {
+        Stats::new(
+            &nanos
+                .iter()
+                .copied()
+                .map(Duration::from_nanos)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn test_stats_odd() {
+        let ret = stats(&[30, 10, 20]);
+        assert_eq!(ret.samples, 3);
+        assert_eq!(ret.min, 10.0);
+        assert_eq!(ret.median, 20.0);
+        assert_eq!(ret.mean, 20.0);
+    }
+
+    #[test]
+    fn test_stats_even() {
+        let ret = stats(&[40, 10, 20, 30]);
+        assert_eq!(ret.median, 25.0);
+        assert_eq!(ret.mean, 25.0);
+        assert_eq!(ret.stddev, 125.0_f64.sqrt());
+    }
+
+    #[test]
+    fn test_median_change() {
+        assert_eq!(median_change(&stats(&[100]), &stats(&[110])), 10.0);
+        assert_eq!(median_change(&stats(&[100]), &stats(&[50])), -50.0);
+    }
+
+    #[test]
+    fn test_median_change_from_zero() {
+        assert_eq!(median_change(&stats(&[0]), &stats(&[0])), 0.0);
+        assert!(median_change(&stats(&[0]), &stats(&[1])) > 10.0);
+    }
+}
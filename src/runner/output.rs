@@ -0,0 +1,122 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::os::fd::FromRawFd;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use super::batch::{RunResult, RunStatus};
+use crate::{Answer, AoCError, AoCResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// A single JSON array of every result
+    Json,
+    /// One JSON object per line
+    NdJson,
+}
+
+impl FromStr for OutputFormat {
+    type Err = AoCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "ndjson" => Ok(Self::NdJson),
+            _ => Err(AoCError::new(format!("Unknown output format {s}"))),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub year: &'a str,
+    pub day: &'a str,
+    pub part: u8,
+    pub example: bool,
+    pub status: &'static str,
+    pub answer: Option<&'a Answer>,
+    pub answer_type: Option<&'static str>,
+    pub expected: Option<&'a str>,
+    pub duration_ns: Option<u128>,
+    pub error: Option<&'a str>,
+}
+
+impl<'a> From<&'a RunResult> for Record<'a> {
+    fn from(result: &'a RunResult) -> Self {
+        let (status, expected, error) = match &result.status {
+            RunStatus::Ok => ("ok", None, None),
+            RunStatus::Error(error) => ("error", None, Some(error.as_str())),
+            RunStatus::Incorrect { expected } => ("incorrect", Some(expected.as_str()), None),
+            RunStatus::Panicked(error) => ("panic", None, Some(error.as_str())),
+            RunStatus::TimedOut => ("timeout", None, None),
+            RunStatus::MissingData => ("missing_data", None, None),
+            RunStatus::Skipped(reason) => ("skipped", None, Some(reason.as_str())),
+        };
+        Self {
+            year: result.year,
+            day: result.day,
+            part: result.part.into(),
+            example: result.example,
+            status,
+            answer: result.answer.as_ref(),
+            answer_type: result.answer.as_ref().map(Answer::kind),
+            expected,
+            duration_ns: result.duration.map(|duration| duration.as_nanos()),
+            error,
+        }
+    }
+}
+
+/// Points stdout at stderr so that any solutions printing out their workings
+/// don't end up mixed in with the machine-readable output.
+/// Returns a handle to the original stdout for the results to be written to.
+pub fn redirect_stdout() -> AoCResult<File> {
+    let fail = |cause| AoCError::new_with_cause("Failed to redirect stdout", cause);
+
+    io::stdout().flush().map_err(fail)?;
+    // SAFETY: These are just file descriptor juggling, and the duplicated
+    // descriptor is only ever owned by the returned file
+    unsafe {
+        let real_stdout = libc::dup(libc::STDOUT_FILENO);
+        if real_stdout < 0 {
+            return Err(fail(io::Error::last_os_error()));
+        }
+        if libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) < 0 {
+            return Err(fail(io::Error::last_os_error()));
+        }
+        Ok(File::from_raw_fd(real_stdout))
+    }
+}
+
+pub fn write_records<W: Write>(
+    out: &mut W,
+    format: OutputFormat,
+    results: &[RunResult],
+) -> AoCResult<()> {
+    let records = results.iter().map(Record::from);
+    let ret = match format {
+        OutputFormat::Text => panic!("Text output isn't machine readable!"),
+        OutputFormat::Json => serde_json::to_writer(&mut *out, &records.collect::<Vec<_>>())
+            .map_err(io::Error::from)
+            .and_then(|_| writeln!(out)),
+        OutputFormat::NdJson => records.into_iter().try_for_each(|record| {
+            serde_json::to_writer(&mut *out, &record).map_err(io::Error::from)?;
+            writeln!(out)
+        }),
+    };
+    ret.and_then(|_| out.flush())
+        .map_err(|cause| AoCError::new_with_cause("Failed to write results", cause))
+}
@@ -0,0 +1,247 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::batch::{RunResult, RunStatus, run_part};
+use crate::{AoCData, AoCDay, Part};
+
+pub struct Job {
+    pub day: &'static AoCDay,
+    pub part: Part,
+    pub data: AoCData,
+    pub example: bool,
+}
+
+/// Solutions get run on their own threads, which only get 2MiB of stack by
+/// default. That's a lot less than the main thread gets, and running out of
+/// stack aborts the whole process rather than panicking, so match the main
+/// thread unless `RUST_MIN_STACK` asks for more.
+const MIN_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+fn stack_size() -> usize {
+    std::env::var("RUST_MIN_STACK")
+        .ok()
+        .and_then(|size| size.parse().ok())
+        .map_or(MIN_STACK_SIZE, |size: usize| size.max(MIN_STACK_SIZE))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+/// Runs the job on its own thread so that if it takes too long we can walk
+/// away from it. There's no way to kill a thread, so it will keep running in
+/// the background until it finishes or the process exits.
+fn run_isolated(job: Job, timeout: Option<Duration>) -> RunResult {
+    let Job {
+        day,
+        part,
+        data,
+        example,
+    } = job;
+    let (tx, rx) = mpsc::channel();
+    let start = Instant::now();
+    let spawned = thread::Builder::new()
+        .name(format!("{}-{}-{part}", day.year, day.day))
+        .stack_size(stack_size())
+        .spawn(move || {
+            let ret = panic::catch_unwind(AssertUnwindSafe(|| run_part(day, part, data, example)));
+            // If nobody's listening any more we timed out, so who cares
+            let _ = tx.send(ret);
+        });
+    if let Err(err) = spawned {
+        return RunResult::new(
+            day,
+            part,
+            example,
+            RunStatus::Error(format!("failed to spawn thread: {err}")),
+        );
+    }
+
+    let ret = match timeout {
+        Some(timeout) => rx.recv_timeout(timeout),
+        None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    match ret {
+        Ok(Ok(result)) => result,
+        Ok(Err(payload)) => {
+            let mut result = RunResult::new(
+                day,
+                part,
+                example,
+                RunStatus::Panicked(panic_message(payload)),
+            );
+            result.duration = Some(start.elapsed());
+            result
+        }
+        Err(RecvTimeoutError::Timeout) => {
+            let mut result = RunResult::new(day, part, example, RunStatus::TimedOut);
+            result.duration = Some(start.elapsed());
+            result
+        }
+        Err(RecvTimeoutError::Disconnected) => RunResult::new(
+            day,
+            part,
+            example,
+            RunStatus::Error("worker thread vanished".to_owned()),
+        ),
+    }
+}
+
+/// Runs all the jobs across a number of worker threads, returning the results
+/// in the same order as the jobs were given
+pub fn run_jobs(
+    jobs: Vec<Job>,
+    workers: NonZeroUsize,
+    timeout: Option<Duration>,
+) -> Vec<RunResult> {
+    let num_jobs = jobs.len();
+    let queue = Arc::new(Mutex::new(
+        jobs.into_iter().enumerate().collect::<VecDeque<_>>(),
+    ));
+    let (tx, rx) = mpsc::channel();
+
+    let workers = (0..workers.get().min(num_jobs))
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            let tx = tx.clone();
+            thread::spawn(move || {
+                loop {
+                    let Some((i, job)) = queue.lock().unwrap().pop_front() else {
+                        break;
+                    };
+                    tx.send((i, run_isolated(job, timeout))).unwrap();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    drop(tx);
+
+    let mut results: Vec<Option<RunResult>> = vec![None; num_jobs];
+    for (i, result) in rx {
+        results[i] = Some(result);
+    }
+    for worker in workers {
+        worker.join().expect("Worker threads should never panic");
+    }
+    results
+        .into_iter()
+        .map(|result| result.expect("Every job must have a result"))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::num::NonZeroUsize;
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{Job, run_jobs};
+    use crate::runner::batch::RunStatus;
+    use crate::{Answer, AoCData, AoCDay, AoCMeta, AoCPart, AoCResult, DataIn, Part};
+
+    const META: AoCMeta = AoCMeta {
+        slow: false,
+        interactive: false,
+        needs_terminal: false,
+        requires_params: false,
+        broken: None,
+    };
+
+    const fn part(func: fn(DataIn) -> AoCResult<Answer>) -> AoCPart {
+        AoCPart {
+            main: func,
+            example: func,
+            params: &[],
+            example_params: &[],
+            meta: META,
+            example_meta: META,
+        }
+    }
+
+    fn first_line(mut data: DataIn) -> AoCResult<Answer> {
+        Ok(data.next().unwrap().into())
+    }
+
+    fn panics(_: DataIn) -> AoCResult<Answer> {
+        panic!("oh no");
+    }
+
+    fn sleeps(_: DataIn) -> AoCResult<Answer> {
+        thread::sleep(Duration::from_secs(5));
+        Ok(Answer::NoAnswer)
+    }
+
+    static DAY: AoCDay = AoCDay {
+        year: "2000",
+        day: "1",
+        part_1: part(first_line),
+        part_2: Some(part(panics)),
+    };
+
+    static SLOW_DAY: AoCDay = AoCDay {
+        year: "2000",
+        day: "2",
+        part_1: part(sleeps),
+        part_2: None,
+    };
+
+    fn job(day: &'static AoCDay, part: Part, line: &str) -> Job {
+        Job {
+            day,
+            part,
+            data: AoCData::new_from_line(line.to_owned()),
+            example: false,
+        }
+    }
+
+    #[test]
+    fn test_panic() {
+        let ret = run_jobs(vec![job(&DAY, Part::Part2, "")], NonZeroUsize::MIN, None);
+        assert_eq!(ret[0].status, RunStatus::Panicked("oh no".to_owned()));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let ret = run_jobs(
+            vec![job(&SLOW_DAY, Part::Part1, "")],
+            NonZeroUsize::MIN,
+            Some(Duration::from_millis(50)),
+        );
+        assert_eq!(ret[0].status, RunStatus::TimedOut);
+    }
+
+    #[test]
+    fn test_order() {
+        let jobs = (0..20)
+            .map(|i| job(&DAY, Part::Part1, &i.to_string()))
+            .collect();
+        let ret = run_jobs(jobs, NonZeroUsize::new(4).unwrap(), None);
+        let answers: Vec<_> = ret
+            .into_iter()
+            .map(|result| result.answer.unwrap().to_string())
+            .collect();
+        let expected: Vec<_> = (0..20).map(|i| i.to_string()).collect();
+        assert_eq!(answers, expected);
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use itertools::Itertools;
+
+use crate::utils::data::data_dir;
+use crate::{AoCError, AoCResult};
+
+fn licence_header() -> String {
+    // Saves pulling in chrono just to find out what year it is
+    let now = humantime::format_rfc3339(SystemTime::now()).to_string();
+    let year = &now[..4];
+    format!(
+        "\
+// Copyright (c) {year} Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+"
+    )
+}
+
+fn day_template() -> String {
+    format!(
+        "\
+{header}
+use aoc_macros::solution;
+
+#[solution(part = 1)]
+pub fn part_1(data: crate::DataIn) -> crate::AoCResult<String> {{
+    let ret = data.count();
+    Ok(ret.to_string())
+}}
+
+#[solution(part = 2)]
+pub fn part_2(data: crate::DataIn) -> crate::AoCResult<String> {{
+    let ret = data.count();
+    Ok(ret.to_string())
+}}
+",
+        header = licence_header(),
+    )
+}
+
+fn read(path: &Path) -> AoCResult<String> {
+    fs::read_to_string(path).map_err(|cause| {
+        AoCError::new_with_cause(format!("Failed to read {}", path.display()), cause)
+    })
+}
+
+fn write(path: &Path, contents: &str) -> AoCResult<()> {
+    fs::write(path, contents).map_err(|cause| {
+        AoCError::new_with_cause(format!("Failed to write {}", path.display()), cause)
+    })
+}
+
+/// Creates a file that must not already exist
+fn create(path: &Path, contents: &str) -> AoCResult<()> {
+    File::create_new(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|cause| {
+            AoCError::new_with_cause(format!("Failed to create {}", path.display()), cause)
+        })
+}
+
+/// Adds `pub mod {name};` to a list of module declarations, keeping them in
+/// order. If it's already there but commented out, it gets uncommented.
+/// Returns None if the module was already declared.
+fn insert_module(contents: &str, name: &str) -> Option<String> {
+    let decl = format!("pub mod {name};");
+    let commented = format!("// {decl}");
+
+    let module_name = |line: &str| {
+        line.trim_start_matches("// ")
+            .strip_prefix("pub mod ")
+            .map(|rest| rest.trim_end_matches(';').to_owned())
+    };
+
+    let mut lines = contents.lines().map(|line| line.to_owned()).collect_vec();
+    if lines.contains(&decl) {
+        return None;
+    } else if let Some(line) = lines.iter_mut().find(|line| **line == commented) {
+        *line = decl;
+    } else {
+        let pos = lines
+            .iter()
+            .rposition(|line| module_name(line).is_some_and(|other| *other < *name))
+            .map(|pos| pos + 1)
+            .or_else(|| lines.iter().position(|line| module_name(line).is_some()))
+            .unwrap_or(lines.len());
+        lines.insert(pos, decl);
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// `insert_module` for a file. Returns false if the module was already
+/// declared.
+fn add_module(path: &Path, name: &str) -> AoCResult<bool> {
+    match insert_module(&read(path)?, name) {
+        Some(contents) => write(path, &contents).map(|_| true),
+        None => Ok(false),
+    }
+}
+
+/// Sets up everything needed to start working on a new day, returning every
+/// file that was created or modified
+pub fn new_day(year: u16, day: u8) -> AoCResult<Vec<PathBuf>> {
+    if !(2015..=2038).contains(&year) {
+        return Err(AoCError::new(format!("{year} is not a valid year!")));
+    } else if !(1..=25).contains(&day) {
+        return Err(AoCError::new(format!("{day} is not a valid day!")));
+    }
+
+    let aoc_root: PathBuf = [".", "src", "aoc"].iter().collect();
+    let aoc_mod = aoc_root.with_extension("rs");
+    if !aoc_mod.exists() {
+        return Err(AoCError::new(
+            "Can't find src/aoc.rs, are you in the right directory?",
+        ));
+    }
+
+    let year_dir = aoc_root.join(format!("y{year}"));
+    let year_mod = year_dir.join("mod.rs");
+    let day_file = year_dir.join(format!("day{day:02}.rs"));
+    if day_file.exists() {
+        return Err(AoCError::new(format!(
+            "{} already exists, refusing to overwrite it!",
+            day_file.display()
+        )));
+    }
+
+    let mut changed = Vec::new();
+
+    if !year_dir.exists() {
+        fs::create_dir_all(&year_dir).map_err(|cause| {
+            AoCError::new_with_cause(format!("Failed to create {}", year_dir.display()), cause)
+        })?;
+    }
+    if !year_mod.exists() {
+        create(&year_mod, &licence_header())?;
+        changed.push(year_mod.clone());
+    }
+    // The day has to exist before anything declares it, or a failure part way
+    // through would leave the tree unable to compile
+    create(&day_file, &day_template())?;
+    changed.push(day_file);
+    if add_module(&aoc_mod, &format!("y{year}"))? {
+        changed.push(aoc_mod);
+    }
+    if add_module(&year_mod, &format!("day{day:02}"))? && !changed.contains(&year_mod) {
+        changed.push(year_mod);
+    }
+
+    let example_dir = data_dir().join(year.to_string()).join("example");
+    let example_file = example_dir.join(format!("{day:02}.txt"));
+    if !example_file.exists() {
+        fs::create_dir_all(&example_dir).map_err(|cause| {
+            AoCError::new_with_cause(format!("Failed to create {}", example_dir.display()), cause)
+        })?;
+        create(&example_file, "")?;
+        changed.push(example_file);
+    }
+
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::insert_module;
+
+    const Y2019: &str = "\
+pub mod computer;
+pub mod day01;
+pub mod day09;
+// pub mod day10;
+pub mod day11;
+// . . .
+pub mod day17;
+// pub mod day18;
+pub mod day19;
+";
+
+    #[test]
+    fn test_already_declared() {
+        assert_eq!(insert_module(Y2019, "day11"), None);
+    }
+
+    #[test]
+    fn test_uncomments() {
+        let ret = insert_module(Y2019, "day10").unwrap();
+        assert_eq!(ret, Y2019.replace("// pub mod day10;", "pub mod day10;"));
+    }
+
+    #[test]
+    fn test_inserts_in_order() {
+        let ret = insert_module(Y2019, "day14").unwrap();
+        assert_eq!(
+            ret,
+            Y2019.replace("pub mod day11;\n", "pub mod day11;\npub mod day14;\n")
+        );
+    }
+
+    #[test]
+    fn test_inserts_after_commented() {
+        let ret = insert_module(Y2019, "day20").unwrap();
+        assert_eq!(ret, Y2019.to_owned() + "pub mod day20;\n");
+    }
+
+    #[test]
+    fn test_inserts_first() {
+        let ret = insert_module(Y2019, "aaa").unwrap();
+        assert_eq!(ret, "pub mod aaa;\n".to_owned() + Y2019);
+    }
+
+    #[test]
+    fn test_empty_file() {
+        let header = "// Copyright\n";
+        assert_eq!(
+            insert_module(header, "day01").unwrap(),
+            "// Copyright\npub mod day01;\n"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::fmt::Display;
+
+use itertools::Itertools;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// A picture that has to be read by a human, eg letters drawn with `#`s
+    Grid(String),
+    /// The answer has to be worked out by looking at what the solution printed
+    NoAnswer,
+}
+
+impl Answer {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Integer(_) => "integer",
+            Self::Text(_) => "text",
+            Self::Grid(_) => "grid",
+            Self::NoAnswer => "none",
+        }
+    }
+
+    /// Whether this is the same as an answer that's been written down
+    /// somewhere, ignoring any trailing whitespace. Returns None if there's
+    /// no way to tell, eg if there's no answer or the expected answer is the
+    /// letters in a picture rather than the picture itself.
+    pub fn matches(&self, expected: &str) -> Option<bool> {
+        match self {
+            Self::Integer(value) => Some(expected.trim() == value.to_string()),
+            Self::Text(value) => Some(expected.trim() == value.trim()),
+            Self::Grid(grid) => {
+                let expected = expected.trim_matches('\n');
+                expected.contains('\n').then(|| {
+                    grid.trim_matches('\n')
+                        .lines()
+                        .map(str::trim_end)
+                        .eq(expected.lines().map(str::trim_end))
+                })
+            }
+            Self::NoAnswer => None,
+        }
+    }
+
+    /// Fits the answer on one line, for tables and the like
+    pub fn summary(&self) -> String {
+        match self {
+            Self::Grid(grid) => {
+                let (width, height) = grid
+                    .trim_matches('\n')
+                    .lines()
+                    .fold((0, 0), |(width, height), line| {
+                        (width.max(line.chars().count()), height + 1)
+                    });
+                format!("[{width}x{height} picture]")
+            }
+            Self::NoAnswer => "[see output]".to_owned(),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => value.fmt(f),
+            Self::Text(value) => value.fmt(f),
+            Self::Grid(grid) => grid.trim_matches('\n').fmt(f),
+            Self::NoAnswer => Ok(()),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Self::Integer(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::Integer(value as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Self::Integer(value as i128)
+    }
+}
+
+/// Most solutions still turn their answer into a string before returning it,
+/// so this works out what it was originally: numbers become integers, anything
+/// multi-line is a picture, and an empty string means there's nothing to check.
+/// Numbers that wouldn't print the same way again, eg `0123`, are left as text.
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        if value.is_empty() {
+            Self::NoAnswer
+        } else if value.trim_matches('\n').contains('\n') {
+            Self::Grid(value)
+        } else if let Some(number) = value
+            .parse::<i128>()
+            .ok()
+            .filter(|number| number.to_string() == value)
+        {
+            Self::Integer(number)
+        } else {
+            Self::Text(value)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        value.to_owned().into()
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::NoAnswer, Into::into)
+    }
+}
+
+impl FromIterator<String> for Answer {
+    /// Builds a picture out of its rows
+    fn from_iter<T: IntoIterator<Item = String>>(iter: T) -> Self {
+        Self::Grid(iter.into_iter().join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Answer;
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(Answer::from("123"), Answer::Integer(123));
+        assert_eq!(Answer::from("-5"), Answer::Integer(-5));
+        assert_eq!(Answer::from("0123"), Answer::Text("0123".to_owned()));
+        assert_eq!(Answer::from("+5"), Answer::Text("+5".to_owned()));
+        assert_eq!(Answer::from("0123").to_string(), "0123");
+    }
+}
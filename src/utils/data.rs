@@ -8,28 +8,92 @@
  * <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
  * See the Licence for the specific language governing permissions and limitations under the Licence.
  */
+use std::env;
+use std::error::Error;
+use std::fmt::{self, Display};
 use std::fs;
+use std::io;
 use std::iter::FusedIterator;
-use std::path::PathBuf;
+use std::ops::{Deref, Range};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
 
-use crate::{AoCError, AoCResult, InputPartitioner};
+use itertools::Itertools;
 
-type Line = String;
-type Lines = Vec<Line>;
-type LinesIter = <Lines as IntoIterator>::IntoIter;
+use crate::utils::error::Context;
+use crate::utils::sections::SectionParsers;
+use crate::utils::tokens;
+use crate::{AoCError, AoCResult};
 
-pub struct AoCDataIterator(LinesIter);
+type Buffer = Arc<str>;
+type LineRanges = Arc<[Range<usize>]>;
+
+/// A line of the input that shares the buffer it came from rather than
+/// having its own copy
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SharedLine {
+    buffer: Buffer,
+    range: Range<usize>,
+}
+
+impl Deref for SharedLine {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.buffer[self.range.clone()]
+    }
+}
+
+impl AsRef<str> for SharedLine {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl Display for SharedLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl fmt::Debug for SharedLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl From<SharedLine> for String {
+    fn from(value: SharedLine) -> Self {
+        value.to_string()
+    }
+}
+
+/// Hands out the lines of an `AoCData` as `String`s for the days that want to
+/// own them, or as borrowed `&str`s via `next_line` and friends for those that
+/// don't
+#[derive(Clone)]
+pub struct AoCDataIterator {
+    buffer: Buffer,
+    lines: LineRanges,
+    front: usize,
+    back: usize,
+    /// How many lines have been taken from the front so far
+    taken: usize,
+    /// How many lines of the whole input came before this one started
+    first_line: usize,
+}
 
 impl Iterator for AoCDataIterator {
-    type Item = Line;
+    type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        self.next_line().map(str::to_owned)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+        let len = self.back - self.front;
+        (len, Some(len))
     }
 }
 
@@ -37,30 +101,271 @@ impl FusedIterator for AoCDataIterator {}
 impl ExactSizeIterator for AoCDataIterator {}
 impl DoubleEndedIterator for AoCDataIterator {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.0.next_back()
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.buffer[self.lines[self.back].clone()].to_owned())
     }
 }
 
 impl AoCDataIterator {
+    fn next_range(&mut self) -> Option<Range<usize>> {
+        if self.front == self.back {
+            return None;
+        }
+        let range = self.lines[self.front].clone();
+        self.front += 1;
+        self.taken += 1;
+        Some(range)
+    }
+
+    /// Like `next`, but borrows the line instead of copying it
+    pub fn next_line(&mut self) -> Option<&str> {
+        let range = self.next_range()?;
+        Some(&self.buffer[range])
+    }
+
+    /// Turns the remaining lines into reference counted slices of the input
+    pub fn shared(mut self) -> impl Iterator<Item = SharedLine> {
+        std::iter::from_fn(move || {
+            let range = self.next_range()?;
+            Some(SharedLine {
+                buffer: self.buffer.clone(),
+                range,
+            })
+        })
+    }
+
+    /// Splits the remaining lines on blank lines. Each section shares the
+    /// original input instead of copying it.
     pub fn partition(self) -> impl Iterator<Item = AoCData> {
-        InputPartitioner::new(self, |line| !line.is_empty()).map(|data| data.into())
+        self.split_on(str::is_empty)
+    }
+
+    /// Splits the remaining lines on every line that `is_separator` picks
+    /// out, eg `|line| line == "---"`. The separators themselves are dropped.
+    pub fn split_on<P>(mut self, mut is_separator: P) -> impl Iterator<Item = AoCData>
+    where
+        P: FnMut(&str) -> bool,
+    {
+        std::iter::from_fn(move || {
+            if self.front == self.back {
+                return None;
+            }
+            let first_line = self.first_line + self.taken;
+            let mut lines = Vec::new();
+            while let Some(range) = self.next_range() {
+                if is_separator(&self.buffer[range.clone()]) {
+                    break;
+                }
+                lines.push(range);
+            }
+            Some(AoCData {
+                buffer: self.buffer.clone(),
+                lines: lines.into(),
+                first_line,
+            })
+        })
+    }
+
+    /// Splits the remaining lines on blank lines, which must give exactly `N`
+    /// sections, eg `let [rules, updates] = data.sections()?;`
+    pub fn sections<const N: usize>(self) -> AoCResult<[AoCDataIterator; N]> {
+        self.sections_by(str::is_empty)
+    }
+
+    /// Like `sections`, but split with `split_on`
+    pub fn sections_by<const N: usize, P>(self, is_separator: P) -> AoCResult<[AoCDataIterator; N]>
+    where
+        P: FnMut(&str) -> bool,
+    {
+        let sections: Vec<_> = self
+            .split_on(is_separator)
+            .map(AoCData::into_iter)
+            .collect();
+        sections.try_into().map_err(|sections: Vec<_>| {
+            AoCError::new(format!(
+                "Expected {N} sections in the input but found {}",
+                sections.len()
+            ))
+        })
+    }
+
+    /// Splits the remaining lines on blank lines and hands each section to its
+    /// own parser, eg
+    /// `let (grid, moves) = data.parse_sections((Grid::new_from_chars, parse_moves))?;`
+    pub fn parse_sections<P: SectionParsers>(self, parsers: P) -> AoCResult<P::Output> {
+        self.parse_sections_by(str::is_empty, parsers)
+    }
+
+    /// Like `parse_sections`, but split with `split_on`
+    pub fn parse_sections_by<F, P>(self, is_separator: F, parsers: P) -> AoCResult<P::Output>
+    where
+        F: FnMut(&str) -> bool,
+        P: SectionParsers,
+    {
+        let sections: Vec<_> = self
+            .split_on(is_separator)
+            .map(AoCData::into_iter)
+            .collect();
+        if sections.len() != P::COUNT {
+            return Err(AoCError::new(format!(
+                "Expected {} sections in the input but found {}",
+                P::COUNT,
+                sections.len()
+            )));
+        }
+        parsers.parse(sections)
+    }
+
+    pub fn parse<F: FromStr>(mut self) -> impl Iterator<Item = Result<F, F::Err>> {
+        std::iter::from_fn(move || self.next_line().map(str::parse))
+    }
+
+    /// Checks every remaining line is the same length, eg before turning the
+    /// input into a grid, and returns that length
+    pub fn check_rectangular(&self) -> AoCResult<usize> {
+        let mut lines = self.remaining().zip(self.line_number() + 1..);
+        let Some((first, first_number)) = lines.next() else {
+            return Ok(0);
+        };
+        let width = first.chars().count();
+        for (line, number) in lines {
+            let len = line.chars().count();
+            if len != width {
+                return Err(AoCError::new(format!(
+                    "Line {number} is {len} characters long but line {first_number} is {width}"
+                )));
+            }
+        }
+        Ok(width)
+    }
+
+    pub fn check_line_count(&self, expected: usize) -> AoCResult<()> {
+        let count = self.len();
+        if count == expected {
+            Ok(())
+        } else {
+            Err(AoCError::new(format!(
+                "Expected {expected} lines of input but there are {count}"
+            )))
+        }
+    }
+
+    /// Checks that every remaining line is made up of only `allowed`
+    pub fn check_chars(&self, allowed: &str) -> AoCResult<()> {
+        for (line, number) in self.remaining().zip(self.line_number() + 1..) {
+            if let Some((c, column)) = line.chars().zip(1..).find(|(c, _)| !allowed.contains(*c)) {
+                return Err(AoCError::new(format!(
+                    "Unexpected character {c:?} on line {number} column {column}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Borrows the lines that haven't been taken yet
+    pub fn remaining(&self) -> impl Iterator<Item = &str> {
+        self.lines[self.front..self.back]
+            .iter()
+            .map(|range| &self.buffer[range.clone()])
     }
 
-    pub fn parse<F: FromStr>(self) -> impl Iterator<Item = Result<F, F::Err>> {
-        self.map(|line| line.parse())
+    /// The number of the line that was last returned, counting from 1
+    pub fn line_number(&self) -> usize {
+        self.first_line + self.taken
+    }
+
+    /// Like `map`, but any errors get told which line they came from
+    pub fn map_lines<T, E, F>(mut self, mut f: F) -> impl Iterator<Item = AoCResult<T>>
+    where
+        E: Into<Box<dyn Error>>,
+        F: FnMut(&str) -> Result<T, E>,
+    {
+        std::iter::from_fn(move || {
+            let range = self.next_range()?;
+            let number = self.line_number();
+            let line = &self.buffer[range];
+            Some(f(line).with_context(|| format!("Line {number} ({line:?})")))
+        })
+    }
+
+    /// Like `parse`, but any errors get told which line they came from
+    pub fn parse_lines<F>(self) -> impl Iterator<Item = AoCResult<F>>
+    where
+        F: FromStr,
+        F::Err: Into<Box<dyn Error>>,
+    {
+        self.map_lines(str::parse)
+    }
+
+    /// Every integer on each line, see `tokens::ints`
+    pub fn ints<T>(self) -> impl Iterator<Item = AoCResult<Vec<T>>>
+    where
+        T: FromStr,
+        T::Err: Into<Box<dyn Error>>,
+    {
+        self.map_lines(tokens::ints)
+    }
+
+    /// Exactly `N` integers from each line
+    pub fn ints_array<T, const N: usize>(self) -> impl Iterator<Item = AoCResult<[T; N]>>
+    where
+        T: FromStr,
+        T::Err: Into<Box<dyn Error>>,
+    {
+        self.map_lines(tokens::ints_array)
+    }
+
+    /// Every integer in the whole input, ignoring where the lines are
+    pub fn all_ints<T>(self) -> impl Iterator<Item = AoCResult<T>>
+    where
+        T: FromStr,
+        T::Err: Into<Box<dyn Error>>,
+    {
+        self.ints().flatten_ok()
+    }
+
+    pub fn words(mut self) -> impl Iterator<Item = Vec<String>> {
+        std::iter::from_fn(move || {
+            let line = self.next_line()?;
+            Some(tokens::words(line).map(str::to_owned).collect())
+        })
+    }
+
+    /// Splits each line in two at the first `sep`
+    pub fn split_pairs(self, sep: &str) -> impl Iterator<Item = AoCResult<(String, String)>> {
+        self.map_lines(move |line| {
+            tokens::split_pair(line, sep).map(|(a, b)| (a.to_owned(), b.to_owned()))
+        })
     }
 }
 
+/// The puzzle input, stored as one buffer plus where each line is in it so
+/// that cloning it is cheap
 #[derive(Clone, Debug, Default)]
-pub struct AoCData(Lines);
+pub struct AoCData {
+    buffer: Buffer,
+    lines: LineRanges,
+    /// Where this starts in the whole input if it's been split up
+    first_line: usize,
+}
 
 impl IntoIterator for AoCData {
-    type Item = Line;
+    type Item = String;
 
     type IntoIter = AoCDataIterator;
 
     fn into_iter(self) -> Self::IntoIter {
-        AoCDataIterator(self.0.into_iter())
+        AoCDataIterator {
+            back: self.lines.len(),
+            buffer: self.buffer,
+            lines: self.lines,
+            front: 0,
+            taken: 0,
+            first_line: self.first_line,
+        }
     }
 }
 
@@ -69,45 +374,345 @@ impl AoCData {
         vec![line].into()
     }
 
+    /// Tidies up the input as it goes, so that it doesn't matter where it was
+    /// saved or copied from: any byte order mark is skipped, trailing
+    /// whitespace is trimmed from every line and blank lines at the end are
+    /// dropped. Tabs are left alone but get a warning, since puzzles never
+    /// use them.
     pub fn new_from_data(data: String) -> Self {
-        Self(data.lines().map(|line| line.to_owned()).collect())
+        let start = data.as_ptr() as usize;
+        let mut lines: Vec<_> = data
+            .strip_prefix('\u{feff}')
+            .unwrap_or(&data)
+            .lines()
+            .map(|line| {
+                let offset = line.as_ptr() as usize - start;
+                offset..offset + line.trim_end().len()
+            })
+            .collect();
+        while lines.last().is_some_and(Range::is_empty) {
+            lines.pop();
+        }
+        if let Some(number) = lines
+            .iter()
+            .position(|range| data[range.clone()].contains('\t'))
+        {
+            log::warn!("Line {} of the input has a tab in it", number + 1);
+        }
+        Self {
+            buffer: data.into(),
+            lines: lines.into(),
+            first_line: 0,
+        }
     }
 
     pub fn new_from_file(year: &str, day: &str, example: bool) -> AoCResult<Self> {
-        let mut data_path: PathBuf = [".", "data", year].iter().collect();
+        DataSource::find(year, day, example)
+            .ok_or_else(|| AoCError::new(format!("No data found for {year} day {day:0>2}!")))?
+            .load()
+    }
+
+    /// Borrows each line without consuming the data
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(|range| &self.buffer[range.clone()])
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+impl From<Vec<String>> for AoCData {
+    fn from(value: Vec<String>) -> Self {
+        let mut buffer = String::with_capacity(value.iter().map(|line| line.len() + 1).sum());
+        let lines = value
+            .iter()
+            .map(|line| {
+                let start = buffer.len();
+                buffer.push_str(line);
+                buffer.push('\n');
+                start..start + line.len()
+            })
+            .collect();
+        Self {
+            buffer: buffer.into(),
+            lines,
+            first_line: 0,
+        }
+    }
+}
+
+impl FromIterator<String> for AoCData {
+    fn from_iter<T: IntoIterator<Item = String>>(iter: T) -> Self {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
+}
+
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Where all the puzzle inputs live. Defaults to `./data`, but can be
+/// overridden with the `AOC_DATA_DIR` environment variable or `set_data_dir`
+pub fn data_dir() -> &'static Path {
+    DATA_DIR.get_or_init(|| {
+        env::var_os("AOC_DATA_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| [".", "data"].iter().collect())
+    })
+}
+
+/// Must be called before anything tries to read any data
+pub fn set_data_dir(path: PathBuf) -> AoCResult<()> {
+    DATA_DIR
+        .set(path)
+        .map_err(|_| AoCError::new("The data directory has already been set"))
+}
+
+fn read_file(path: &Path) -> AoCResult<String> {
+    fs::read_to_string(path).map_err(|cause| {
+        AoCError::new_with_cause(format!("Failed to read {}", path.to_string_lossy()), cause)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataSource {
+    /// One of the files in the data directory
+    DataFile {
+        path: PathBuf,
+        example: bool,
+    },
+    /// Some other file someone's pointed us at
+    Path(PathBuf),
+    Stdin,
+}
+
+impl DataSource {
+    /// Finds the data file for a given day in the data directory.
+    /// If there's no example file the real input is used instead, with a
+    /// warning, since a lot of days don't have examples that make sense.
+    pub fn find(year: &str, day: &str, example: bool) -> Option<Self> {
+        let mut data_path = data_dir().join(year);
         if example {
             data_path.push("example");
         }
         data_path.push(format!("{:0>2}", day));
         data_path.set_extension("txt");
-        if !data_path.exists() {
-            if example {
-                return Self::new_from_file(year, day, false);
-            } else {
-                return Err(AoCError::new(format!(
-                    "No data found for {year} day {day:0>2}!"
-                )));
+        if data_path.exists() {
+            Some(Self::DataFile {
+                path: data_path,
+                example,
+            })
+        } else if example {
+            let ret = Self::find(year, day, false);
+            if ret.is_some() {
+                log::warn!(
+                    "No example data for {year} day {day:0>2}, using the real input instead"
+                );
             }
+            ret
+        } else {
+            None
         }
-        fs::read_to_string(&data_path)
-            .map_err(|cause| {
-                AoCError::new_with_cause(
-                    format!("Failed to read {}", data_path.to_string_lossy()),
-                    cause,
-                )
-            })
-            .map(Self::new_from_data)
+    }
+
+    /// Treats "-" as meaning stdin, as is traditional
+    pub fn new_from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            _ => Self::Path(arg.into()),
+        }
+    }
+
+    pub fn is_example(&self) -> bool {
+        matches!(self, Self::DataFile { example: true, .. })
+    }
+
+    /// Whether the known answers actually apply to this data
+    pub fn has_answers(&self) -> bool {
+        matches!(self, Self::DataFile { .. })
+    }
+
+    pub fn read(&self) -> AoCResult<String> {
+        match self {
+            Self::DataFile { path, .. } | Self::Path(path) => read_file(path),
+            Self::Stdin => io::read_to_string(io::stdin())
+                .map_err(|cause| AoCError::new_with_cause("Failed to read stdin", cause)),
+        }
+    }
+
+    pub fn load(&self) -> AoCResult<AoCData> {
+        self.read().map(AoCData::new_from_data)
     }
 }
 
-impl From<Vec<String>> for AoCData {
-    fn from(value: Vec<String>) -> Self {
-        Self(value)
+impl Display for DataSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DataFile { path, .. } | Self::Path(path) => path.display().fmt(f),
+            Self::Stdin => write!(f, "stdin"),
+        }
     }
 }
 
-impl FromIterator<String> for AoCData {
-    fn from_iter<T: IntoIterator<Item = String>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use super::AoCData;
+
+    #[test]
+    fn test_line_numbers_in_sections() {
+        let data: AoCData = ["1", "2", "", "3", "x", "", "5"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let [first, second, _] = data.into_iter().sections().unwrap();
+        assert!(first.parse_lines::<u32>().all(|res| res.is_ok()));
+        let err = second
+            .parse_lines::<u32>()
+            .find_map(Result::err)
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Line 5 (\"x\")"), "{err}");
+    }
+    #[test]
+    fn test_check_line_numbers_in_sections() {
+        let data: AoCData = ["ab", "cd", "", "ef", "ghi", "", "j."]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        let [_, second, third] = data.into_iter().sections().unwrap();
+        let err = second.check_rectangular().unwrap_err().to_string();
+        assert!(
+            err.contains("Line 5 is 3 characters long but line 4 is 2"),
+            "{err}"
+        );
+        let err = third.check_chars("abcdefghij").unwrap_err().to_string();
+        assert!(err.contains("on line 7 column 2"), "{err}");
+    }
+
+    fn make_data(lines: &[&str]) -> AoCData {
+        lines.iter().copied().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn test_ints() {
+        let data = make_data(&["1,2", "x=-3 y=4"]);
+        let ret: Vec<Vec<i32>> = data.into_iter().ints().try_collect().unwrap();
+        assert_eq!(ret, [vec![1, 2], vec![-3, 4]]);
+    }
+
+    #[test]
+    fn test_ints_bad_number() {
+        let data = make_data(&["1 2", "3 300"]);
+        let err = data
+            .into_iter()
+            .ints::<u8>()
+            .find_map(Result::err)
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Line 2 (\"3 300\")"), "{err}");
+        assert!(err.contains("Invalid number 300"), "{err}");
+    }
+
+    #[test]
+    fn test_all_ints() {
+        let data = make_data(&["1 2", "", "3"]);
+        let ret: Vec<u32> = data.into_iter().all_ints().try_collect().unwrap();
+        assert_eq!(ret, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_words() {
+        let data = make_data(&["a  b", " c "]);
+        let ret: Vec<_> = data.into_iter().words().collect();
+        assert_eq!(ret, [vec!["a", "b"], vec!["c"]]);
+    }
+
+    #[test]
+    fn test_split_pairs() {
+        let data = make_data(&["a: b", "c: d: e", "f"]);
+        let mut ret = data.into_iter().split_pairs(": ");
+        assert_eq!(
+            ret.next().unwrap().unwrap(),
+            ("a".to_owned(), "b".to_owned())
+        );
+        assert_eq!(
+            ret.next().unwrap().unwrap(),
+            ("c".to_owned(), "d: e".to_owned())
+        );
+        let err = ret.next().unwrap().unwrap_err().to_string();
+        assert!(err.contains("Line 3 (\"f\")"), "{err}");
+    }
+
+    #[test]
+    fn test_sections_count() {
+        let data = make_data(&["1", "", "2"]);
+        let Err(err) = data.clone().into_iter().sections::<3>() else {
+            panic!("two sections aren't three");
+        };
+        assert_eq!(
+            err.to_string(),
+            "Expected 3 sections in the input but found 2"
+        );
+        let Err(err) = data.into_iter().sections::<1>() else {
+            panic!("two sections aren't one");
+        };
+        assert_eq!(
+            err.to_string(),
+            "Expected 1 sections in the input but found 2"
+        );
+    }
+
+    #[test]
+    fn test_sections_by() {
+        let data = make_data(&["1", "", "2", "---", "3"]);
+        let [first, second] = data.into_iter().sections_by(|line| line == "---").unwrap();
+        assert_eq!(first.collect_vec(), ["1", "", "2"]);
+        assert_eq!(second.collect_vec(), ["3"]);
+    }
+
+    fn normalised(data: &str) -> Vec<String> {
+        AoCData::new_from_data(data.to_owned())
+            .lines()
+            .map(str::to_owned)
+            .collect()
+    }
+
+    #[test]
+    fn test_strips_bom() {
+        assert_eq!(normalised("\u{feff}ab\ncd\n"), ["ab", "cd"]);
+    }
+
+    #[test]
+    fn test_trims_trailing_whitespace() {
+        assert_eq!(normalised("  ab  \ncd\t\n"), ["  ab", "cd"]);
+    }
+
+    #[test]
+    fn test_trims_carriage_returns() {
+        assert_eq!(normalised("ab\r\ncd\r\n"), ["ab", "cd"]);
+        assert_eq!(normalised("ab\r\r\ncd\r"), ["ab", "cd"]);
+    }
+
+    #[test]
+    fn test_drops_trailing_blank_lines() {
+        assert_eq!(normalised("ab\n\n  \n\r\n"), ["ab"]);
+        assert!(normalised("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_keeps_interior_blank_lines() {
+        let data = AoCData::new_from_data("ab\n \ncd\n\n".to_owned());
+        assert_eq!(data.len(), 3);
+        let sections: Vec<Vec<String>> = data
+            .into_iter()
+            .partition()
+            .map(|section| section.into_iter().collect())
+            .collect();
+        assert_eq!(sections, [vec!["ab"], vec!["cd"]]);
     }
 }
@@ -0,0 +1,392 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::fmt::{Debug, Display};
+use std::ops::{Index, IndexMut};
+
+use itertools::Itertools;
+
+use crate::{CharGrid, CommonGrid, Coord2D, Coordinate, DisplayGrid, FlatGrid};
+
+/// An alternative to `Grid` that keeps everything in one row-major
+/// `Vec` instead of a `HashMap`, so lookups are just index maths and iterating
+/// always goes left to right, top to bottom.
+#[derive(Debug, Clone)]
+pub struct DenseGrid<Item> {
+    cells: Vec<Item>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl<Item> DenseGrid<Item> {
+    fn new_from_cells(cells: Vec<Item>, width: u32, height: u32) -> Self {
+        let size = Self::validate_dimensions(width, height);
+        assert_eq!(cells.len(), size, "Grid has the wrong number of cells");
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn new_with_initialiser<F: Fn() -> Item>(width: u32, height: u32, init: F) -> Self {
+        let size = Self::validate_dimensions(width, height);
+        Self::new_from_cells((0..size).map(|_| init()).collect(), width, height)
+    }
+
+    fn validate_dimensions(width: u32, height: u32) -> usize {
+        assert!(width <= (i32::MAX as u32), "grid is too wide!");
+        assert!(height <= (i32::MAX as u32), "grid is too tall!");
+        (width as usize)
+            .checked_mul(height as usize)
+            .expect("Grid is too big!")
+    }
+
+    fn index_of(&self, coord: &Coord2D) -> Option<usize> {
+        self.check_coord(coord)
+            .then(|| coord.y as usize * self.width as usize + coord.x as usize)
+    }
+
+    pub fn check_coord(&self, coord: &Coord2D) -> bool {
+        (coord.x >= 0 && coord.y >= 0)
+            && ((coord.x as u32) < self.width && (coord.y as u32) < self.height)
+    }
+
+    pub fn get_row(&self, row: u32) -> &[Item] {
+        assert!(row < self.height);
+        let start = row as usize * self.width as usize;
+        &self.cells[start..start + self.width as usize]
+    }
+
+    pub fn get_column(&self, column: u32) -> impl Iterator<Item = &Item> + '_ {
+        assert!(column < self.width);
+        self.cells
+            .iter()
+            .skip(column as usize)
+            .step_by(self.width as usize)
+    }
+
+    pub fn get_neighbour_coords(
+        &self,
+        coord: Coord2D,
+        diagonal: bool,
+    ) -> impl Iterator<Item = Coord2D> + '_ {
+        (-1..=1)
+            .cartesian_product(-1..=1)
+            .map(|c| c.into())
+            .filter(move |c: &Coord2D| {
+                if (c.x == 0 && c.y == 0) || (!diagonal && c.x != 0 && c.y != 0) {
+                    return false;
+                }
+                true
+            })
+            .map(move |c| c + coord)
+            .filter(|c| self.check_coord(c))
+    }
+
+    pub fn get_neighbours(
+        &self,
+        coord: Coord2D,
+        diagonal: bool,
+    ) -> impl Iterator<Item = (Coord2D, &Item)> + '_ {
+        self.get_neighbour_coords(coord, diagonal)
+            .map(move |target| (target, &self[target]))
+    }
+
+    pub fn get_neighbour_coords_filtered<'a, P>(
+        &'a self,
+        coord: Coord2D,
+        diagonal: bool,
+        predicate: P,
+    ) -> impl Iterator<Item = Coord2D> + 'a
+    where
+        P: Fn(&Coord2D, &Item) -> bool + 'a,
+    {
+        self.get_neighbours(coord, diagonal)
+            .filter(move |(coord, value)| predicate(coord, value))
+            .map(|(coord, _)| coord)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = Coord2D> + use<Item> {
+        let width = self.width;
+        (0..self.height as i32).flat_map(move |y| (0..width as i32).map(move |x| (x, y).into()))
+    }
+
+    /// Returns the first match, reading left to right, top to bottom
+    pub fn find<P>(&self, predicate: P) -> Option<Coord2D>
+    where
+        P: FnMut(&(Coord2D, &Item)) -> bool,
+    {
+        self.iter().find(predicate).map(|(coord, _)| coord)
+    }
+
+    /// Unlike `Grid`, the coordinates are worked out as it goes so you get
+    /// them by value
+    pub fn iter(&self) -> impl Iterator<Item = (Coord2D, &Item)> {
+        self.keys().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord2D, &mut Item)> {
+        self.keys().zip(&mut self.cells)
+    }
+}
+
+impl<Item: Clone> DenseGrid<Item> {
+    pub fn new_filled(width: u32, height: u32, default: Item) -> Self {
+        let size = Self::validate_dimensions(width, height);
+        Self::new_from_cells(vec![default; size], width, height)
+    }
+
+    pub fn fill(&mut self, value: Item) {
+        self.cells.fill(value);
+    }
+}
+
+impl<Item: Default + Debug> DenseGrid<Item> {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self::new_with_initialiser(width, height, Default::default)
+    }
+}
+
+impl<Item> Index<Coord2D> for DenseGrid<Item> {
+    type Output = Item;
+
+    fn index(&self, index: Coord2D) -> &Self::Output {
+        let i = self
+            .index_of(&index)
+            .unwrap_or_else(|| panic!("{index} is outside the grid"));
+        &self.cells[i]
+    }
+}
+
+impl<Item> IndexMut<Coord2D> for DenseGrid<Item> {
+    fn index_mut(&mut self, index: Coord2D) -> &mut Self::Output {
+        let i = self
+            .index_of(&index)
+            .unwrap_or_else(|| panic!("{index} is outside the grid"));
+        &mut self.cells[i]
+    }
+}
+
+impl<Item: Display> Display for DenseGrid<Item> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.do_fmt(f)
+    }
+}
+
+/// Any order will do, but every coordinate from (0, 0) to the biggest one must
+/// be there
+impl<Item> FromIterator<(Coord2D, Item)> for DenseGrid<Item> {
+    fn from_iter<T: IntoIterator<Item = (Coord2D, Item)>>(iter: T) -> Self {
+        let mut max = Coord2D::MIN;
+        let items = iter
+            .into_iter()
+            .inspect(|(coord, _)| {
+                assert!(
+                    coord.x >= 0 && coord.y >= 0,
+                    "Negative coordinates are not allowed"
+                );
+                max = max.get_max(coord);
+            })
+            .collect_vec();
+        if items.is_empty() {
+            return Self::new_from_cells(Vec::new(), 0, 0);
+        }
+
+        let width = max.x as u32 + 1;
+        let height = max.y as u32 + 1;
+        let mut cells: Vec<Option<Item>> = Vec::new();
+        cells.resize_with(Self::validate_dimensions(width, height), || None);
+        for (coord, item) in items {
+            cells[coord.y as usize * width as usize + coord.x as usize] = Some(item);
+        }
+        let cells = cells
+            .into_iter()
+            .map(|cell| cell.expect("Every cell in the grid must be given a value"))
+            .collect();
+        Self::new_from_cells(cells, width, height)
+    }
+}
+
+pub struct IntoIter<Item> {
+    cells: std::iter::Enumerate<std::vec::IntoIter<Item>>,
+    width: usize,
+}
+
+impl<Item> Iterator for IntoIter<Item> {
+    type Item = (Coord2D, Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, item) = self.cells.next()?;
+        Some((
+            ((i % self.width) as i32, (i / self.width) as i32).into(),
+            item,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cells.size_hint()
+    }
+}
+
+impl<Item> IntoIterator for DenseGrid<Item> {
+    type Item = (Coord2D, Item);
+    type IntoIter = IntoIter<Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            cells: self.cells.into_iter().enumerate(),
+            width: self.width as usize,
+        }
+    }
+}
+
+impl<Item> CommonGrid<Coord2D, Item> for DenseGrid<Item> {
+    fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    fn get(&self, k: &Coord2D) -> Option<&Item> {
+        self.index_of(k).map(|i| &self.cells[i])
+    }
+
+    fn get_mut(&mut self, k: &Coord2D) -> Option<&mut Item> {
+        self.index_of(k).map(|i| &mut self.cells[i])
+    }
+
+    /// Unlike `Grid` this can't grow, so setting anything outside it panics
+    fn set(&mut self, k: Coord2D, v: Item) -> Option<Item> {
+        Some(std::mem::replace(&mut self[k], v))
+    }
+
+    /// Every cell always has a value, so `default` is never used. Panics if
+    /// `k` is outside the grid, the same as `set`.
+    fn get_or_set(&mut self, k: &Coord2D, _default: Item) -> &Item {
+        &self[*k]
+    }
+
+    fn max_key(&self) -> Coord2D {
+        (self.width as i32 - 1, self.height as i32 - 1).into()
+    }
+
+    fn min_key(&self) -> Coord2D {
+        (0, 0).into()
+    }
+}
+
+impl<Item> FlatGrid<Coord2D, Item> for DenseGrid<Item> {
+    /// Skips all the coordinate juggling since the lines are already in order
+    fn new_from_lines<Iter, Inner>(data: Iter) -> Self
+    where
+        Inner: IntoIterator<Item = Item>,
+        Iter: Iterator<Item = Inner>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in data {
+            let start = cells.len();
+            cells.extend(line);
+            let len = cells.len() - start;
+            let width = *width.get_or_insert(len);
+            assert_eq!(
+                len, width,
+                "Row {height} is {len} long but the first row is {width}"
+            );
+            height += 1;
+        }
+        let width = width
+            .unwrap_or_default()
+            .try_into()
+            .expect("grid is too wide!");
+        Self::new_from_cells(cells, width, height)
+    }
+}
+
+impl<Item: TryFrom<char>> CharGrid<Coord2D, Item> for DenseGrid<Item> {}
+
+impl<Item: Display> DisplayGrid<Coord2D, Item> for DenseGrid<Item> {
+    fn get_for_display(&self, key: &Coord2D) -> Option<&dyn Display> {
+        self.get(key).map(|i| i as &dyn Display)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::DenseGrid;
+    use crate::{CommonGrid, Coord2D, FlatGrid};
+
+    /// The grid the other grid types test with too:
+    /// ```text
+    /// 1 2 3
+    /// 4 5 6
+    /// ```
+    pub(crate) fn make_grid() -> DenseGrid<u32> {
+        DenseGrid::new_from_lines([[1, 2, 3], [4, 5, 6]].into_iter())
+    }
+
+    #[test]
+    fn test_iter_order() {
+        let grid = make_grid();
+        let ret: Vec<_> = grid.iter().map(|(coord, item)| (coord, *item)).collect();
+        assert_eq!(
+            ret,
+            vec![
+                ((0, 0).into(), 1),
+                ((1, 0).into(), 2),
+                ((2, 0).into(), 3),
+                ((0, 1).into(), 4),
+                ((1, 1).into(), 5),
+                ((2, 1).into(), 6),
+            ]
+        );
+        let owned: Vec<_> = grid.clone().into_iter().collect();
+        assert_eq!(ret, owned);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = make_grid();
+        assert_eq!(grid.get_row(1), [4, 5, 6]);
+        assert!(grid.get_column(2).eq(&[3, 6]));
+    }
+
+    #[test]
+    fn test_get_and_set() {
+        let mut grid = make_grid();
+        assert_eq!(grid.get(&(2, 1).into()), Some(&6));
+        assert_eq!(grid.get(&(3, 0).into()), None);
+        assert_eq!(grid.get(&(-1, 0).into()), None);
+        assert_eq!(grid.set((1, 1).into(), 10), Some(5));
+        assert_eq!(grid[Coord2D { x: 1, y: 1 }], 10);
+        assert_eq!(grid.get_or_set(&(0, 0).into(), 20), &1);
+        assert_eq!(grid.find(|(_, item)| **item == 10), Some((1, 1).into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_or_set_outside() {
+        make_grid().get_or_set(&(3, 0).into(), 0);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let grid = make_grid();
+        let mut cells: Vec<_> = grid.clone().into_iter().collect();
+        cells.reverse();
+        let ret: DenseGrid<u32> = cells.into_iter().collect();
+        assert_eq!(ret.width, 3);
+        assert_eq!(ret.height, 2);
+        assert!(ret.iter().eq(grid.iter()));
+    }
+}
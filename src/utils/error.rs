@@ -29,18 +29,31 @@ pub struct AoCError {
     message: String,
 }
 
+/// `{}` puts the whole chain of causes on one line, `{:#}` puts each cause on
+/// its own line
 impl Display for AoCError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.message)?;
-        if let Some(ref cause) = self.cause {
-            write!(f, ": {}", *cause)?;
+        if !f.alternate() {
+            if let Some(ref cause) = self.cause {
+                write!(f, ": {}", *cause)?;
+            }
+            return Ok(());
+        }
+        let mut cause = self.source();
+        while let Some(err) = cause {
+            match err.downcast_ref::<AoCError>() {
+                Some(err) => write!(f, "\n  caused by: {}", err.message)?,
+                None => write!(f, "\n  caused by: {err}")?,
+            }
+            cause = err.source();
         }
         Ok(())
     }
 }
 
 impl Error for AoCError {
-    fn cause(&self) -> Option<&dyn Error> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         self.cause.as_deref()
     }
 }
@@ -76,14 +89,43 @@ impl AoCError {
     }
 }
 
+/// Adds an explanation to a failure, eg
+/// `line.split_once(' ').context("No space in the line")?`
+pub trait Context<T> {
+    fn context<S: Into<String>>(self, message: S) -> AoCResult<T>;
+
+    /// The same as `context`, but only builds the message if it's needed
+    fn with_context<S: Into<String>, F: FnOnce() -> S>(self, f: F) -> AoCResult<T>;
+}
+
+impl<T, E: Into<Box<dyn Error>>> Context<T> for Result<T, E> {
+    fn context<S: Into<String>>(self, message: S) -> AoCResult<T> {
+        self.map_err(|cause| AoCError::new_with_cause(message, cause))
+    }
+
+    fn with_context<S: Into<String>, F: FnOnce() -> S>(self, f: F) -> AoCResult<T> {
+        self.map_err(|cause| AoCError::new_with_cause(f(), cause))
+    }
+}
+
+impl<T> Context<T> for Option<T> {
+    fn context<S: Into<String>>(self, message: S) -> AoCResult<T> {
+        self.ok_or_else(|| AoCError::new(message))
+    }
+
+    fn with_context<S: Into<String>, F: FnOnce() -> S>(self, f: F) -> AoCResult<T> {
+        self.ok_or_else(|| AoCError::new(f()))
+    }
+}
+
 impl From<std::num::ParseIntError> for AoCError {
     fn from(value: std::num::ParseIntError) -> Self {
-        Self::new_with_cause("failed to parse:", value)
+        Self::new_with_cause("failed to parse", value)
     }
 }
 
 impl From<std::num::ParseFloatError> for AoCError {
     fn from(value: std::num::ParseFloatError) -> Self {
-        Self::new_with_cause("failed to parse:", value)
+        Self::new_with_cause("failed to parse", value)
     }
 }
@@ -0,0 +1,182 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+//! Example files that contain several cases can say what each case should
+//! give, eg a multi line example can look like
+//!
+//! ```text
+//! (()) => part1=0
+//! ))((((( => part1=3 part2=1
+//! ```
+//!
+//! and each section of a partitioned example can contain a line like
+//!
+//! ```text
+//! => part1=4 part2=10
+//! ```
+
+use crate::{Answer, AoCData, AoCDayFn, AoCError, AoCResult, Part};
+
+const MARKER: &str = "=>";
+
+pub struct ExampleCase {
+    pub data: AoCData,
+    pub expected: Option<String>,
+}
+
+/// Finds the answer for the part out of something like `part1=4 part2=10`
+fn parse_expected(notes: &str, part: Part) -> AoCResult<Option<String>> {
+    let mut ret = None;
+    for note in notes.split_whitespace() {
+        let (key, value) = note
+            .split_once('=')
+            .ok_or_else(|| AoCError::new(format!("Expected partN=answer, got {note}")))?;
+        match key {
+            "part1" | "part2" => {
+                if key == format!("part{part}") {
+                    ret = Some(value.to_owned());
+                }
+            }
+            _ => return Err(AoCError::new(format!("Unknown example annotation {key}"))),
+        }
+    }
+    Ok(ret)
+}
+
+impl ExampleCase {
+    /// A single line case, with the expected answers after a `=>`
+    pub fn from_line(line: String, part: Part) -> AoCResult<(String, Self)> {
+        let (input, expected) = match line.rsplit_once(&format!(" {MARKER} ")) {
+            Some((input, notes)) => (input.to_owned(), parse_expected(notes, part)?),
+            None => (line, None),
+        };
+        Ok((
+            input.clone(),
+            Self {
+                data: AoCData::new_from_line(input),
+                expected,
+            },
+        ))
+    }
+
+    /// A multi line case, where any line starting with `=>` is the expected
+    /// answers rather than part of the input
+    pub fn from_lines(lines: AoCData, part: Part) -> AoCResult<Self> {
+        let mut expected = None;
+        let mut data = Vec::new();
+        for line in lines {
+            match line.strip_prefix(MARKER) {
+                Some(notes) => expected = parse_expected(notes, part)?.or(expected),
+                None => data.push(line),
+            }
+        }
+        Ok(Self {
+            data: data.into(),
+            expected,
+        })
+    }
+
+    /// Runs the case, printing the result and whether it was what was
+    /// expected. Returns false if it wasn't.
+    pub fn run(self, main: AoCDayFn) -> AoCResult<bool> {
+        let res = main(self.data.into_iter())?;
+        println!("Result: {res}");
+        let Some(expected) = self.expected else {
+            return Ok(true);
+        };
+        let verdict = res.matches(&expected);
+        match verdict {
+            Some(true) => println!("Pass"),
+            Some(false) => println!("FAIL: expected {expected}"),
+            None => println!("Can't check this answer, expected {expected}"),
+        }
+        Ok(verdict.unwrap_or(true))
+    }
+}
+
+pub fn check_failures(failures: usize, total: usize) -> AoCResult<Answer> {
+    if failures > 0 {
+        Err(AoCError::new(format!(
+            "{failures} of {total} examples gave the wrong answer"
+        )))
+    } else {
+        Ok(Answer::NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ExampleCase;
+    use crate::{
+        Answer, AoCData, AoCResult, DataIn, Part, multi_line_example, partitioned_example,
+    };
+
+    /// How long the first line is, which is enough to check single line cases
+    fn first_len(mut data: DataIn) -> AoCResult<Answer> {
+        Ok(data.next().unwrap_or_default().len().into())
+    }
+
+    /// How many lines there are, to check the annotations have been removed
+    fn line_count(data: DataIn) -> AoCResult<Answer> {
+        Ok(data.count().into())
+    }
+
+    fn make_data(lines: &[&str]) -> AoCData {
+        lines.iter().copied().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn test_from_line_splits_on_last_marker() {
+        let line = "a => b => part1=3 part2=4".to_owned();
+        let (input, case) = ExampleCase::from_line(line, Part::Part2).unwrap();
+        assert_eq!(input, "a => b");
+        assert_eq!(case.expected.as_deref(), Some("4"));
+    }
+
+    #[test]
+    fn test_from_line_without_marker() {
+        let (input, case) = ExampleCase::from_line("(())".to_owned(), Part::Part1).unwrap();
+        assert_eq!(input, "(())");
+        assert_eq!(case.expected, None);
+    }
+
+    #[test]
+    fn test_bad_annotations() {
+        for line in ["x => part3=1", "x => part1=1 oops"] {
+            assert!(
+                ExampleCase::from_line(line.to_owned(), Part::Part1).is_err(),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_lines_removes_annotation() {
+        let data = make_data(&["ab", "=> part1=2", "cd"]);
+        let case = ExampleCase::from_lines(data, Part::Part1).unwrap();
+        assert_eq!(case.expected.as_deref(), Some("2"));
+        assert!(case.run(line_count).unwrap());
+    }
+
+    #[test]
+    fn test_multi_line_example() {
+        let data = make_data(&["abc => part1=3", "de => part1=2"]);
+        assert!(multi_line_example(data.clone().into_iter(), first_len, Part::Part1).is_ok());
+        let data = make_data(&["abc => part1=3", "de => part1=5"]);
+        assert!(multi_line_example(data.into_iter(), first_len, Part::Part1).is_err());
+    }
+
+    #[test]
+    fn test_partitioned_example() {
+        let data = make_data(&["a", "b", "=> part2=2", "", "c", "=> part2=1"]);
+        assert!(partitioned_example(data.into_iter(), line_count, Part::Part2).is_ok());
+        let data = make_data(&["a", "b", "=> part2=2", "", "c", "=> part2=3"]);
+        assert!(partitioned_example(data.into_iter(), line_count, Part::Part2).is_err());
+    }
+}
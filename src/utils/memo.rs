@@ -0,0 +1,144 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A cache for recursive functions that lives as long as the solve that
+/// created it, rather than for the whole process, eg
+///
+/// ```ignore
+/// fn count(memo: &mut Memo<Key, usize>, id: Key, store: &Store) -> usize {
+///     memo.get_or_insert_with(id, |memo| {
+///         store[&id].iter().map(|&next| count(memo, next, store)).sum()
+///     })
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Hash + Eq,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            cache: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the value for `key`, working it out with `f` if it's not been
+    /// seen before. `f` gets the memo back so it can recurse.
+    pub fn get_or_insert_with<F>(&mut self, key: K, f: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let value = f(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Like `get_or_insert_with` but for things that can fail. Failures
+    /// aren't cached.
+    pub fn try_get_or_insert_with<F, E>(&mut self, key: K, f: F) -> Result<V, E>
+    where
+        F: FnOnce(&mut Self) -> Result<V, E>,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            return Ok(value.clone());
+        }
+        let value = f(self)?;
+        self.cache.insert(key, value.clone());
+        Ok(value)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::Memo;
+
+    type Graph = HashMap<&'static str, Vec<&'static str>>;
+
+    /// How many ways there are to get from `node` to `out`
+    fn paths(memo: &mut Memo<&'static str, usize>, node: &'static str, graph: &Graph) -> usize {
+        if node == "out" {
+            return 1;
+        }
+        memo.get_or_insert_with(node, |memo| {
+            graph[node]
+                .iter()
+                .map(|&next| paths(memo, next, graph))
+                .sum()
+        })
+    }
+
+    #[test]
+    fn test_fresh_memo_per_input() {
+        let first: Graph = [
+            ("you", vec!["a", "b"]),
+            ("a", vec!["out"]),
+            ("b", vec!["a", "out"]),
+        ]
+        .into_iter()
+        .collect();
+        // Same keys, different answers, so a shared cache would get this wrong
+        let second: Graph = [("you", vec!["a"]), ("a", vec!["out"]), ("b", vec!["out"])]
+            .into_iter()
+            .collect();
+        assert_eq!(paths(&mut Memo::new(), "you", &first), 3);
+        assert_eq!(paths(&mut Memo::new(), "you", &second), 1);
+    }
+
+    #[test]
+    fn test_errors_arent_cached() {
+        let mut memo: Memo<u32, u32> = Memo::new();
+        let ret: Result<u32, &str> = memo.try_get_or_insert_with(1, |_| Err("nope"));
+        assert_eq!(ret, Err("nope"));
+        assert!(memo.is_empty());
+        let ret: Result<u32, &str> = memo.try_get_or_insert_with(1, |_| Ok(5));
+        assert_eq!(ret, Ok(5));
+        assert_eq!(memo.get(&1), Some(&5));
+    }
+}
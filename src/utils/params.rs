@@ -0,0 +1,99 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use itertools::Itertools;
+
+use crate::{AoCError, AoCParam, AoCResult};
+
+static OVERRIDES: OnceLock<HashMap<String, String>> = OnceLock::new();
+
+/// Something that can be passed to a solution as a parameter
+pub trait ParamValue: Sized {
+    /// Overrides live for the rest of the process, so they can be borrowed
+    fn parse_param(value: &'static str) -> Result<Self, String>;
+}
+
+macro_rules! impl_param_value {
+    ($($ty:ty),*) => {
+        $(
+            impl ParamValue for $ty {
+                fn parse_param(value: &'static str) -> Result<Self, String> {
+                    value.parse().map_err(|err| format!("{err}"))
+                }
+            }
+        )*
+    };
+}
+
+impl_param_value!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, String
+);
+
+impl ParamValue for &'static str {
+    fn parse_param(value: &'static str) -> Result<Self, String> {
+        Ok(value)
+    }
+}
+
+/// Parses a `name=value` argument
+pub fn parse_override(arg: &str) -> Result<(String, String), String> {
+    arg.split_once('=')
+        .filter(|(name, _)| !name.is_empty())
+        .map(|(name, value)| (name.to_owned(), value.to_owned()))
+        .ok_or_else(|| format!("expected name=value, got {arg}"))
+}
+
+pub fn set_overrides(overrides: HashMap<String, String>) -> AoCResult<()> {
+    OVERRIDES
+        .set(overrides)
+        .map_err(|_| AoCError::new("Parameter overrides have already been set"))
+}
+
+fn get_override(name: &str) -> Option<&'static str> {
+    OVERRIDES
+        .get()
+        .and_then(|overrides| overrides.get(name))
+        .map(|value| value.as_str())
+}
+
+pub fn overridden_names() -> impl Iterator<Item = &'static str> {
+    OVERRIDES
+        .get()
+        .into_iter()
+        .flat_map(|overrides| overrides.keys().map(|name| name.as_str()))
+}
+
+/// Fetches the value for a solution's parameter, using the override from the
+/// command line if there is one
+pub fn get<T: ParamValue>(name: &str, default: T) -> AoCResult<T> {
+    match get_override(name) {
+        Some(value) => T::parse_param(value).map_err(|err| {
+            AoCError::new(format!(
+                "Invalid value {value:?} for parameter {name}: {err}"
+            ))
+        }),
+        None => Ok(default),
+    }
+}
+
+/// Shows what each parameter is going to be set to, eg `width=7, height=12`
+pub fn describe(params: &[AoCParam]) -> String {
+    params
+        .iter()
+        .map(|param| {
+            let value = get_override(param.name)
+                .map(|value| value.to_owned())
+                .unwrap_or_else(|| (param.default)());
+            format!("{}={value}", param.name)
+        })
+        .join(", ")
+}
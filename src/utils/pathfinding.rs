@@ -0,0 +1,370 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::utils::regions::in_bounds;
+use crate::{CommonGrid, Connectivity, Coord2D, Coordinate2D};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Step<C> {
+    cost: u64,
+    coord: C,
+}
+
+impl<C: Ord> Ord for Step<C> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Backwards so the heap gives us the cheapest first
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.coord.cmp(&other.coord))
+    }
+}
+
+impl<C: Ord> PartialOrd for Step<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// How far every reachable cell is from the nearest source, and how to get
+/// there
+#[derive(Debug, Clone, Default)]
+pub struct DistanceMap<C = Coord2D> {
+    distances: HashMap<C, u64>,
+    came_from: HashMap<C, C>,
+}
+
+impl<C: Coordinate2D> DistanceMap<C> {
+    pub fn get(&self, coord: &C) -> Option<u64> {
+        self.distances.get(coord).copied()
+    }
+
+    pub fn contains(&self, coord: &C) -> bool {
+        self.distances.contains_key(coord)
+    }
+
+    pub fn len(&self) -> usize {
+        self.distances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.distances.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&C, u64)> {
+        self.distances
+            .iter()
+            .map(|(coord, distance)| (coord, *distance))
+    }
+
+    /// One of the shortest routes to `coord`, starting with the source it came
+    /// from and ending with `coord`
+    pub fn path_to(&self, coord: &C) -> Option<Vec<C>> {
+        if !self.contains(coord) {
+            return None;
+        }
+        let mut ret = vec![*coord];
+        let mut coord = coord;
+        while let Some(prev) = self.came_from.get(coord) {
+            ret.push(*prev);
+            coord = prev;
+        }
+        ret.reverse();
+        Some(ret)
+    }
+}
+
+struct Search<C> {
+    connectivity: Connectivity,
+    limit: Option<u64>,
+    target: Option<C>,
+    /// Whether to stay between the grid's min and max keys
+    bounded: bool,
+}
+
+impl<C: Coordinate2D + Ord> Search<C> {
+    fn new(connectivity: Connectivity) -> Self {
+        Self {
+            connectivity,
+            limit: None,
+            target: None,
+            bounded: true,
+        }
+    }
+
+    fn run<G, Item, F>(
+        self,
+        grid: &G,
+        sources: impl IntoIterator<Item = C>,
+        mut cost: F,
+    ) -> DistanceMap<C>
+    where
+        G: CommonGrid<C, Item>,
+        F: FnMut(&C, &Item) -> Option<u64>,
+    {
+        let min = grid.min_key();
+        let max = grid.max_key();
+        let mut ret = DistanceMap::default();
+        let mut queue = BinaryHeap::new();
+        for coord in sources {
+            ret.distances.insert(coord, 0);
+            queue.push(Step { cost: 0, coord });
+        }
+
+        while let Some(Step {
+            cost: current,
+            coord,
+        }) = queue.pop()
+        {
+            if Some(coord) == self.target {
+                break;
+            } else if ret.get(&coord).is_some_and(|best| best < current) {
+                continue;
+            }
+            for neighbour in self.connectivity.neighbours(coord) {
+                if self.bounded && !in_bounds(&neighbour, &min, &max) {
+                    continue;
+                }
+                let Some(step) = grid.get(&neighbour).and_then(|item| cost(&neighbour, item))
+                else {
+                    continue;
+                };
+                let next = current + step;
+                if self.limit.is_some_and(|limit| next > limit)
+                    || ret.get(&neighbour).is_some_and(|best| best <= next)
+                {
+                    continue;
+                }
+                ret.distances.insert(neighbour, next);
+                ret.came_from.insert(neighbour, coord);
+                queue.push(Step {
+                    cost: next,
+                    coord: neighbour,
+                });
+            }
+        }
+        ret
+    }
+}
+
+/// How many steps it takes to get everywhere `passable` allows from the
+/// nearest of `sources`. The sources themselves are always included, whether
+/// they're passable or not.
+pub fn bfs<G, C, Item, P>(
+    grid: &G,
+    sources: impl IntoIterator<Item = C>,
+    connectivity: Connectivity,
+    mut passable: P,
+) -> DistanceMap<C>
+where
+    G: CommonGrid<C, Item>,
+    C: Coordinate2D + Ord,
+    P: FnMut(&C, &Item) -> bool,
+{
+    Search::new(connectivity).run(grid, sources, |coord, item| {
+        passable(coord, item).then_some(1)
+    })
+}
+
+/// Like `bfs`, but `cost` says how much it costs to step onto each cell, or
+/// `None` if you can't
+pub fn dijkstra<G, C, Item, F>(
+    grid: &G,
+    sources: impl IntoIterator<Item = C>,
+    connectivity: Connectivity,
+    cost: F,
+) -> DistanceMap<C>
+where
+    G: CommonGrid<C, Item>,
+    C: Coordinate2D + Ord,
+    F: FnMut(&C, &Item) -> Option<u64>,
+{
+    Search::new(connectivity).run(grid, sources, cost)
+}
+
+/// The cheapest way from `start` to `end`, including both of them, and how
+/// much it costs. Stops searching as soon as it gets there.
+pub fn shortest_path<G, C, Item, F>(
+    grid: &G,
+    start: C,
+    end: C,
+    connectivity: Connectivity,
+    cost: F,
+) -> Option<(u64, Vec<C>)>
+where
+    G: CommonGrid<C, Item>,
+    C: Coordinate2D + Ord,
+    F: FnMut(&C, &Item) -> Option<u64>,
+{
+    let distances = Search {
+        target: Some(end),
+        ..Search::new(connectivity)
+    }
+    .run(grid, [start], cost);
+    Some((distances.get(&end)?, distances.path_to(&end)?))
+}
+
+/// Everything that's at most `steps` away from one of the sources
+pub fn reachable_within<G, C, Item, P>(
+    grid: &G,
+    sources: impl IntoIterator<Item = C>,
+    connectivity: Connectivity,
+    steps: u64,
+    mut passable: P,
+) -> DistanceMap<C>
+where
+    G: CommonGrid<C, Item>,
+    C: Coordinate2D + Ord,
+    P: FnMut(&C, &Item) -> bool,
+{
+    Search {
+        limit: Some(steps),
+        ..Search::new(connectivity)
+    }
+    .run(grid, sources, |coord, item| {
+        passable(coord, item).then_some(1)
+    })
+}
+
+/// Like `reachable_within`, but carries on past the grid's min and max keys,
+/// for grids that go on forever such as `TiledGrid`
+pub fn reachable_within_unbounded<G, C, Item, P>(
+    grid: &G,
+    sources: impl IntoIterator<Item = C>,
+    connectivity: Connectivity,
+    steps: u64,
+    mut passable: P,
+) -> DistanceMap<C>
+where
+    G: CommonGrid<C, Item>,
+    C: Coordinate2D + Ord,
+    P: FnMut(&C, &Item) -> bool,
+{
+    Search {
+        limit: Some(steps),
+        bounded: false,
+        ..Search::new(connectivity)
+    }
+    .run(grid, sources, |coord, item| {
+        passable(coord, item).then_some(1)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{bfs, dijkstra, reachable_within, shortest_path};
+    use crate::{CharGrid, Connectivity, Coord2D, Grid};
+
+    /// S.#.
+    /// .##.
+    /// ...E
+    fn make_maze() -> Grid<char> {
+        Grid::new_from_chars(["S.#.", ".##.", "...E"].map(str::to_owned)).unwrap()
+    }
+
+    fn open(_: &Coord2D, c: &char) -> bool {
+        *c != '#'
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = make_maze();
+        let start = (0, 0).into();
+        let ret = bfs(&grid, [start], Connectivity::Orthogonal, open);
+        assert_eq!(ret.len(), 9);
+        assert_eq!(ret.get(&start), Some(0));
+        assert_eq!(ret.get(&(3, 2).into()), Some(5));
+        assert_eq!(ret.get(&(3, 0).into()), Some(7));
+        assert!(!ret.contains(&(2, 0).into()));
+
+        let ret = bfs(&grid, [start], Connectivity::Diagonal, open);
+        assert_eq!(ret.get(&(3, 2).into()), Some(4));
+    }
+
+    #[test]
+    fn test_path_to() {
+        let grid = make_maze();
+        let ret = bfs(&grid, [(0, 0).into()], Connectivity::Orthogonal, open);
+        let path = ret.path_to(&(3, 0).into()).unwrap();
+        let expected: Vec<Coord2D> = [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (3, 2),
+            (3, 1),
+            (3, 0),
+        ]
+        .map(Coord2D::from)
+        .into();
+        assert_eq!(path, expected);
+        assert_eq!(ret.path_to(&(0, 0).into()), Some(vec![(0, 0).into()]));
+        assert_eq!(ret.path_to(&(2, 1).into()), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let grid = make_maze();
+        // Going through the walls is allowed but expensive
+        let ret = dijkstra(&grid, [(0, 0).into()], Connectivity::Orthogonal, |_, c| {
+            Some(if *c == '#' { 10 } else { 1 })
+        });
+        assert_eq!(ret.len(), 12);
+        assert_eq!(ret.get(&(2, 0).into()), Some(11));
+        assert_eq!(ret.get(&(3, 0).into()), Some(7));
+        assert_eq!(ret.get(&(1, 1).into()), Some(11));
+        assert_eq!(ret.get(&(2, 1).into()), Some(14));
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let grid = make_maze();
+        let cost = |coord: &Coord2D, c: &char| open(coord, c).then_some(1);
+        let (steps, path) = shortest_path(
+            &grid,
+            (0, 0).into(),
+            (3, 2).into(),
+            Connectivity::Orthogonal,
+            cost,
+        )
+        .unwrap();
+        assert_eq!(steps, 5);
+        assert_eq!(path.len(), 6);
+        assert_eq!(
+            shortest_path(
+                &grid,
+                (0, 0).into(),
+                (2, 0).into(),
+                Connectivity::Orthogonal,
+                cost
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reachable_within() {
+        let grid = make_maze();
+        let ret = reachable_within(&grid, [(0, 0).into()], Connectivity::Orthogonal, 3, open);
+        assert_eq!(ret.len(), 5);
+        assert!(ret.iter().all(|(_, steps)| steps <= 3));
+        assert!(!ret.contains(&(3, 2).into()));
+        let ret = reachable_within(
+            &grid,
+            [(0, 0).into(), (3, 0).into()],
+            Connectivity::Orthogonal,
+            1,
+            open,
+        );
+        assert_eq!(ret.len(), 5);
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+//! Support code for `#[derive(AocParse)]`
+
+use std::error::Error;
+use std::str::FromStr;
+
+use regex::Captures;
+
+use crate::{AoCError, AoCResult};
+
+/// Parses one of the captures from a derived pattern into a field
+pub fn parse_capture<T>(captures: &Captures, index: usize, field: &str, input: &str) -> AoCResult<T>
+where
+    T: FromStr,
+    T::Err: Into<Box<dyn Error>>,
+{
+    let value = captures.get(index).map_or("", |capture| capture.as_str());
+    value.parse().map_err(|cause| {
+        AoCError::new_with_cause(format!("Invalid {field} {value:?} in {input:?}"), cause)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_macros::AocParse;
+
+    #[derive(Debug, PartialEq, Eq, AocParse)]
+    #[aoc(pattern = "{x},{y}")]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Debug, PartialEq, Eq, AocParse)]
+    enum Value {
+        #[aoc(pattern = "{0}")]
+        Number(u32),
+        #[aoc(pattern = "{0}")]
+        Wire(String),
+    }
+
+    #[test]
+    fn test_struct() {
+        assert_eq!("3,-4".parse::<Point>().unwrap(), Point { x: 3, y: -4 });
+    }
+
+    #[test]
+    fn test_overlapping_variants() {
+        assert_eq!("123".parse::<Value>().unwrap(), Value::Number(123));
+        assert_eq!(
+            "abc".parse::<Value>().unwrap(),
+            Value::Wire("abc".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_bad_field() {
+        let err = "3,four".parse::<Point>().unwrap_err().to_string();
+        assert!(err.contains("Point.y"), "{err}");
+        assert!(err.contains("\"four\""), "{err}");
+        assert!(err.contains("\"3,four\""), "{err}");
+    }
+
+    #[test]
+    fn test_no_match() {
+        let err = "3".parse::<Point>().unwrap_err().to_string();
+        assert!(err.contains("doesn't match"), "{err}");
+    }
+}
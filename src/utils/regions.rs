@@ -0,0 +1,279 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use itertools::Either;
+
+use crate::{CommonGrid, Coord2D, Coordinate, Coordinate2D, Direction, GridViews};
+
+/// Which cells count as being next to each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Up, down, left and right
+    Orthogonal,
+    /// Diagonals too
+    Diagonal,
+}
+
+impl Connectivity {
+    pub(crate) fn neighbours<C: Coordinate2D>(self, coord: C) -> impl Iterator<Item = C> {
+        match self {
+            Self::Orthogonal => Either::Left(coord.get_neighbours().into_iter()),
+            Self::Diagonal => Either::Right(coord.get_diagonal_neighbours().into_iter()),
+        }
+    }
+}
+
+/// A set of connected cells
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Region {
+    cells: HashSet<Coord2D>,
+}
+
+impl Region {
+    pub fn contains(&self, coord: &Coord2D) -> bool {
+        self.cells.contains(coord)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Coord2D> {
+        self.cells.iter()
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// How many cell edges are on the outside of the region, including
+    /// around any holes in it
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|coord| {
+                [
+                    Direction::North,
+                    Direction::East,
+                    Direction::South,
+                    Direction::West,
+                ]
+                .into_iter()
+                .filter(|dir| !self.contains(&(*coord + dir.to_coord())))
+                .count()
+            })
+            .sum()
+    }
+
+    /// How many straight lines it takes to draw the perimeter, which is the
+    /// same as how many corners it has
+    pub fn sides(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|coord| {
+                [
+                    (Direction::North, Direction::East),
+                    (Direction::East, Direction::South),
+                    (Direction::South, Direction::West),
+                    (Direction::West, Direction::North),
+                ]
+                .into_iter()
+                .filter(|(a, b)| {
+                    let a = a.to_coord();
+                    let b = b.to_coord();
+                    let has_a = self.contains(&(*coord + a));
+                    let has_b = self.contains(&(*coord + b));
+                    let has_diagonal = self.contains(&(*coord + a + b));
+                    // Either an outside corner, or an inside one
+                    (!has_a && !has_b) || (has_a && has_b && !has_diagonal)
+                })
+                .count()
+            })
+            .sum()
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle that
+    /// fits the whole region
+    pub fn bounding_box(&self) -> Option<(Coord2D, Coord2D)> {
+        self.cells.iter().fold(None, |bounds, coord| {
+            Some(match bounds {
+                None => (*coord, *coord),
+                Some((min, max)) => (min.get_min(coord), max.get_max(coord)),
+            })
+        })
+    }
+}
+
+impl FromIterator<Coord2D> for Region {
+    fn from_iter<T: IntoIterator<Item = Coord2D>>(iter: T) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+pub(crate) fn in_bounds<C: Coordinate2D>(coord: &C, min: &C, max: &C) -> bool {
+    let (x, y) = coord.to_tuple();
+    let (min_x, min_y) = min.to_tuple();
+    let (max_x, max_y) = max.to_tuple();
+    (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y)
+}
+
+/// Finds everything that can be reached from `starts` by only stepping on
+/// cells that `predicate` accepts. Starting cells that it doesn't accept are
+/// ignored. Never leaves the area between the grid's min and max keys, even if
+/// the grid has a default value for everywhere else.
+pub fn flood_fill<G, Item, P>(
+    grid: &G,
+    starts: impl IntoIterator<Item = Coord2D>,
+    connectivity: Connectivity,
+    mut predicate: P,
+) -> Region
+where
+    G: CommonGrid<Coord2D, Item>,
+    P: FnMut(&Coord2D, &Item) -> bool,
+{
+    let min = grid.min_key();
+    let max = grid.max_key();
+    let mut accept = |coord: &Coord2D| {
+        in_bounds(coord, &min, &max) && grid.get(coord).is_some_and(|item| predicate(coord, item))
+    };
+
+    let mut cells = HashSet::new();
+    let mut queue: VecDeque<_> = starts.into_iter().collect();
+    while let Some(coord) = queue.pop_front() {
+        if cells.contains(&coord) || !accept(&coord) {
+            continue;
+        }
+        cells.insert(coord);
+        queue.extend(
+            connectivity
+                .neighbours(coord)
+                .filter(|neighbour| !cells.contains(neighbour)),
+        );
+    }
+    Region { cells }
+}
+
+/// Every region in a grid, along with which region each cell is in
+#[derive(Debug, Clone, Default)]
+pub struct Components {
+    pub regions: Vec<Region>,
+    labels: HashMap<Coord2D, usize>,
+}
+
+impl Components {
+    /// The index into `regions` of the region the cell is in
+    pub fn label(&self, coord: &Coord2D) -> Option<usize> {
+        self.labels.get(coord).copied()
+    }
+
+    pub fn region_at(&self, coord: &Coord2D) -> Option<&Region> {
+        self.label(coord).map(|label| &self.regions[label])
+    }
+}
+
+/// Splits the whole grid up into regions of cells that are connected to each
+/// other and that `same` says match
+pub fn components<G, Item, F>(grid: &G, connectivity: Connectivity, same: F) -> Components
+where
+    G: CommonGrid<Coord2D, Item>,
+    F: Fn(&Item, &Item) -> bool,
+{
+    let mut ret = Components::default();
+    if grid.is_empty() {
+        return ret;
+    }
+    for (coord, item) in grid.rect(grid.min_key(), grid.max_key()) {
+        if ret.labels.contains_key(&coord) {
+            continue;
+        }
+        let region = flood_fill(grid, [coord], connectivity, |_, other| same(item, other));
+        let label = ret.regions.len();
+        ret.labels
+            .extend(region.iter().map(|coord| (*coord, label)));
+        ret.regions.push(region);
+    }
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Connectivity, Region, components, flood_fill};
+    use crate::{CharGrid, Coord2D, Grid};
+
+    fn make_grid(lines: &[&str]) -> Grid<char> {
+        Grid::new_from_chars(lines.iter().map(|line| line.to_string())).unwrap()
+    }
+
+    fn region_of(lines: &[&str], c: char) -> Region {
+        make_grid(lines)
+            .iter()
+            .filter(|(_, item)| **item == c)
+            .map(|(coord, _)| *coord)
+            .collect()
+    }
+
+    #[test]
+    fn test_e_shape() {
+        let region = region_of(&["EEEEE", "EXXXX", "EEEEE", "EXXXX", "EEEEE"], 'E');
+        assert_eq!(region.area(), 17);
+        assert_eq!(region.perimeter(), 36);
+        assert_eq!(region.sides(), 12);
+        assert_eq!(region.bounding_box(), Some(((0, 0).into(), (4, 4).into())));
+    }
+
+    #[test]
+    fn test_hole() {
+        let region = region_of(&["OOO", "O.O", "OOO"], 'O');
+        assert_eq!(region.area(), 8);
+        assert_eq!(region.perimeter(), 16);
+        assert_eq!(region.sides(), 8);
+    }
+
+    #[test]
+    fn test_touching_corners() {
+        let region = region_of(
+            &["AAAAAA", "AAABBA", "AAABBA", "ABBAAA", "ABBAAA", "AAAAAA"],
+            'A',
+        );
+        assert_eq!(region.area(), 28);
+        assert_eq!(region.sides(), 12);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = make_grid(&["..#..", "..#..", "###..", "....."]);
+        let start: Coord2D = (0, 0).into();
+        let orthogonal = flood_fill(&grid, [start], Connectivity::Orthogonal, |_, c| *c == '.');
+        assert_eq!(orthogonal.area(), 4);
+        assert!(!orthogonal.contains(&(3, 0).into()));
+        let diagonal = flood_fill(&grid, [start], Connectivity::Diagonal, |_, c| *c == '.');
+        assert_eq!(diagonal.area(), 4);
+        let walls = flood_fill(&grid, [start], Connectivity::Orthogonal, |_, c| *c == '#');
+        assert!(walls.is_empty());
+        let everything = flood_fill(&grid, [start], Connectivity::Orthogonal, |_, _| true);
+        assert_eq!(everything.area(), 20);
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = make_grid(&["AAAA", "BBCD", "BBCC", "EEEC"]);
+        let ret = components(&grid, Connectivity::Orthogonal, |a, b| a == b);
+        assert_eq!(ret.regions.len(), 5);
+        assert_eq!(ret.label(&(0, 1).into()), ret.label(&(1, 2).into()));
+        assert_ne!(ret.label(&(2, 1).into()), ret.label(&(3, 1).into()));
+        let c = ret.region_at(&(2, 1).into()).unwrap();
+        assert_eq!((c.area(), c.perimeter(), c.sides()), (4, 10, 8));
+        let total: usize = ret.regions.iter().map(|r| r.area() * r.perimeter()).sum();
+        assert_eq!(total, 140);
+    }
+}
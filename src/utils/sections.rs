@@ -0,0 +1,118 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::error::Error;
+
+use crate::utils::error::Context;
+use crate::{AoCResult, DataIn};
+
+/// A tuple with a parser for each section of the input, see
+/// `AoCDataIterator::parse_sections`
+pub trait SectionParsers {
+    type Output;
+    const COUNT: usize;
+
+    /// There must be exactly `COUNT` sections
+    fn parse(self, sections: Vec<DataIn>) -> AoCResult<Self::Output>;
+}
+
+macro_rules! impl_section_parsers {
+    ($count:literal; $($index:tt $func:ident $ret:ident $err:ident),+) => {
+        impl<$($func, $ret, $err),+> SectionParsers for ($($func,)+)
+        where
+            $(
+                $func: FnOnce(DataIn) -> Result<$ret, $err>,
+                $err: Into<Box<dyn Error>>,
+            )+
+        {
+            type Output = ($($ret,)+);
+            const COUNT: usize = $count;
+
+            fn parse(self, sections: Vec<DataIn>) -> AoCResult<Self::Output> {
+                let mut sections = sections.into_iter();
+                Ok(($(
+                    (self.$index)(sections.next().expect("the section count is checked first"))
+                        .with_context(|| format!("Section {} of the input", $index + 1))?,
+                )+))
+            }
+        }
+    };
+}
+
+impl_section_parsers!(1; 0 F0 T0 E0);
+impl_section_parsers!(2; 0 F0 T0 E0, 1 F1 T1 E1);
+impl_section_parsers!(3; 0 F0 T0 E0, 1 F1 T1 E1, 2 F2 T2 E2);
+impl_section_parsers!(4; 0 F0 T0 E0, 1 F1 T1 E1, 2 F2 T2 E2, 3 F3 T3 E3);
+impl_section_parsers!(5; 0 F0 T0 E0, 1 F1 T1 E1, 2 F2 T2 E2, 3 F3 T3 E3, 4 F4 T4 E4);
+
+#[cfg(test)]
+mod test {
+    use crate::{AoCData, AoCResult, DataIn};
+
+    fn make_data(lines: &[&str]) -> AoCData {
+        lines.iter().copied().map(str::to_owned).collect()
+    }
+
+    fn numbers(data: DataIn) -> AoCResult<Vec<u32>> {
+        data.parse_lines().collect()
+    }
+
+    fn words(data: DataIn) -> AoCResult<Vec<String>> {
+        Ok(data.collect())
+    }
+
+    #[test]
+    fn test_parse_sections() {
+        let data = make_data(&["1", "2", "", "a", "b"]);
+        let (nums, words) = data.into_iter().parse_sections((numbers, words)).unwrap();
+        assert_eq!(nums, [1, 2]);
+        assert_eq!(words, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_wrong_section_count() {
+        let data = make_data(&["1", "", "a"]);
+        let err = data
+            .into_iter()
+            .parse_sections((numbers, words, words))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "Expected 3 sections in the input but found 2");
+        let data = make_data(&["1", "", "a", "", "b"]);
+        let err = data
+            .into_iter()
+            .parse_sections((numbers, words))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "Expected 2 sections in the input but found 3");
+    }
+
+    #[test]
+    fn test_parse_sections_by() {
+        let data = make_data(&["a", "", "b", "---", "1"]);
+        let (words, nums) = data
+            .into_iter()
+            .parse_sections_by(|line| line == "---", (words, numbers))
+            .unwrap();
+        assert_eq!(words, ["a", "", "b"]);
+        assert_eq!(nums, [1]);
+    }
+
+    #[test]
+    fn test_section_context() {
+        let data = make_data(&["a", "", "1", "x"]);
+        let err = data
+            .into_iter()
+            .parse_sections((words, numbers))
+            .unwrap_err();
+        let err = format!("{err:#}");
+        assert!(err.starts_with("Section 2 of the input"), "{err}");
+        assert!(err.contains("caused by: Line 4 (\"x\")"), "{err}");
+    }
+}
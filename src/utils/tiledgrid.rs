@@ -0,0 +1,227 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::fmt::Display;
+
+use crate::{BigCoord2D, CommonGrid, Connectivity, Coord2D, Coordinate, DisplayGrid, FlatGrid};
+
+/// Treats a grid as if it repeats forever in every direction. Copy (0, 0) is
+/// the original grid, copy (1, 0) is the one immediately to its right, etc.
+#[derive(Debug, Clone)]
+pub struct TiledGrid<G> {
+    grid: G,
+    width: i64,
+    height: i64,
+    /// The first and last tiles that get displayed
+    window: (BigCoord2D, BigCoord2D),
+}
+
+impl<G> TiledGrid<G> {
+    /// The grid must start at (0, 0) and not be empty
+    pub fn new<Item>(grid: G) -> Self
+    where
+        G: FlatGrid<Coord2D, Item>,
+    {
+        assert!(!grid.is_empty(), "Can't tile an empty grid");
+        assert_eq!(
+            grid.min_key(),
+            Coord2D { x: 0, y: 0 },
+            "Tiled grids must start at (0, 0)"
+        );
+        let max = grid.max_key();
+        let origin = BigCoord2D { x: 0, y: 0 };
+        Self {
+            grid,
+            width: i64::from(max.x) + 1,
+            height: i64::from(max.y) + 1,
+            window: (origin, origin),
+        }
+    }
+
+    pub fn base(&self) -> &G {
+        &self.grid
+    }
+
+    pub fn base_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+
+    pub fn into_inner(self) -> G {
+        self.grid
+    }
+
+    pub fn tile_size(&self) -> (i64, i64) {
+        (self.width, self.height)
+    }
+
+    /// Which copy of the grid `coord` is in
+    pub fn tile(&self, coord: &BigCoord2D) -> BigCoord2D {
+        BigCoord2D {
+            x: coord.x.div_euclid(self.width),
+            y: coord.y.div_euclid(self.height),
+        }
+    }
+
+    /// Where `coord` is in the original grid
+    pub fn to_base(&self, coord: &BigCoord2D) -> Coord2D {
+        Coord2D {
+            x: coord.x.rem_euclid(self.width) as i32,
+            y: coord.y.rem_euclid(self.height) as i32,
+        }
+    }
+
+    /// Which tiles to show when the grid is displayed, inclusive
+    pub fn set_window(&mut self, min_tile: BigCoord2D, max_tile: BigCoord2D) {
+        self.window = (min_tile.get_min(&max_tile), min_tile.get_max(&max_tile));
+    }
+
+    pub fn get_neighbour_coords(
+        &self,
+        coord: BigCoord2D,
+        diagonal: bool,
+    ) -> impl Iterator<Item = BigCoord2D> + use<G> {
+        if diagonal {
+            Connectivity::Diagonal
+        } else {
+            Connectivity::Orthogonal
+        }
+        .neighbours(coord)
+    }
+}
+
+/// `min_key`, `max_key` and `len` describe the window rather than the whole
+/// infinite grid. Changing a cell changes it in every copy.
+impl<G, Item> CommonGrid<BigCoord2D, Item> for TiledGrid<G>
+where
+    G: CommonGrid<Coord2D, Item>,
+{
+    fn len(&self) -> usize {
+        let (min, max) = self.window;
+        let tiles = (max.x - min.x + 1) * (max.y - min.y + 1);
+        (tiles * self.width * self.height)
+            .try_into()
+            .expect("Window is too big!")
+    }
+
+    fn is_empty(&self) -> bool {
+        self.grid.is_empty()
+    }
+
+    fn get(&self, k: &BigCoord2D) -> Option<&Item> {
+        self.grid.get(&self.to_base(k))
+    }
+
+    fn get_mut(&mut self, k: &BigCoord2D) -> Option<&mut Item> {
+        let k = self.to_base(k);
+        self.grid.get_mut(&k)
+    }
+
+    fn set(&mut self, k: BigCoord2D, v: Item) -> Option<Item> {
+        let k = self.to_base(&k);
+        self.grid.set(k, v)
+    }
+
+    fn get_or_set(&mut self, k: &BigCoord2D, default: Item) -> &Item {
+        let k = self.to_base(k);
+        self.grid.get_or_set(&k, default)
+    }
+
+    fn min_key(&self) -> BigCoord2D {
+        let min = self.window.0;
+        BigCoord2D {
+            x: min.x * self.width,
+            y: min.y * self.height,
+        }
+    }
+
+    fn max_key(&self) -> BigCoord2D {
+        let max = self.window.1;
+        BigCoord2D {
+            x: (max.x + 1) * self.width - 1,
+            y: (max.y + 1) * self.height - 1,
+        }
+    }
+}
+
+impl<G, Item> DisplayGrid<BigCoord2D, Item> for TiledGrid<G>
+where
+    G: DisplayGrid<Coord2D, Item>,
+{
+    fn get_for_display(&self, key: &BigCoord2D) -> Option<&dyn Display> {
+        self.grid.get_for_display(&self.to_base(key))
+    }
+}
+
+/// `IntoIterator` is only there to pin down what `Item` is, which every
+/// `FlatGrid` already does
+impl<G, Item> Display for TiledGrid<G>
+where
+    G: FlatGrid<Coord2D, Item> + DisplayGrid<Coord2D, Item> + IntoIterator<Item = (Coord2D, Item)>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.do_fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TiledGrid;
+    use crate::utils::densegrid::test;
+    use crate::{BigCoord2D, CommonGrid, DenseGrid, WrappingGrid};
+
+    fn make_grid() -> TiledGrid<DenseGrid<u32>> {
+        TiledGrid::new(test::make_grid())
+    }
+
+    #[test]
+    fn test_tile_and_to_base() {
+        let grid = make_grid();
+        let check = |x, y, tile: (i64, i64), base: (i32, i32)| {
+            let coord = BigCoord2D { x, y };
+            assert_eq!(grid.tile(&coord), tile.into(), "{coord}");
+            assert_eq!(grid.to_base(&coord), base.into(), "{coord}");
+        };
+        check(0, 0, (0, 0), (0, 0));
+        check(2, 1, (0, 0), (2, 1));
+        check(3, 2, (1, 1), (0, 0));
+        check(-1, -1, (-1, -1), (2, 1));
+        check(-3, -2, (-1, -1), (0, 0));
+        check(-4, -3, (-2, -2), (2, 1));
+        check(7, -5, (2, -3), (1, 1));
+    }
+
+    #[test]
+    fn test_get_and_set() {
+        let mut grid = make_grid();
+        assert_eq!(grid.get(&(-1, 0).into()), Some(&3));
+        assert_eq!(grid.set((-2, -1).into(), 10), Some(5));
+        assert_eq!(grid.base().get(&(1, 1).into()), Some(&10));
+        assert_eq!(grid.get(&(301, 101).into()), Some(&10));
+    }
+
+    #[test]
+    fn test_window() {
+        let mut grid = make_grid();
+        assert_eq!(grid.len(), 6);
+        grid.set_window((1, 0).into(), (-1, 1).into());
+        assert_eq!(grid.min_key(), (-3, 0).into());
+        assert_eq!(grid.max_key(), (5, 3).into());
+        assert_eq!(grid.len(), 36);
+        assert_eq!(
+            grid.to_string().trim_end(),
+            "123123123\n456456456\n123123123\n456456456"
+        );
+    }
+
+    #[test]
+    fn test_display_any_grid() {
+        let grid: WrappingGrid<u32> = test::make_grid().into();
+        assert_eq!(TiledGrid::new(grid).to_string().trim_end(), "123\n456");
+    }
+}
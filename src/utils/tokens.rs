@@ -0,0 +1,117 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+//! Pulling the interesting bits out of a line without writing a regex
+
+use std::error::Error;
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::{AoCError, AoCResult};
+
+/// Finds every run of digits in the line. A `-` directly in front of one
+/// makes it negative, unless it's in the middle of a word like `1-3`.
+pub fn int_strs(line: &str) -> impl Iterator<Item = &str> {
+    let bytes = line.as_bytes();
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        let start = pos + bytes[pos..].iter().position(u8::is_ascii_digit)?;
+        let end = bytes[start..]
+            .iter()
+            .position(|c| !c.is_ascii_digit())
+            .map_or(bytes.len(), |len| start + len);
+        pos = end;
+        let negative = start > 0
+            && bytes[start - 1] == b'-'
+            && (start == 1 || !bytes[start - 2].is_ascii_alphanumeric());
+        Some(&line[if negative { start - 1 } else { start }..end])
+    })
+}
+
+/// Parses every integer in the line, eg `Button A: X+94, Y+34` gives `[94, 34]`
+pub fn ints<T>(line: &str) -> AoCResult<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Box<dyn Error>>,
+{
+    int_strs(line)
+        .map(|num| {
+            num.parse()
+                .map_err(|cause| AoCError::new_with_cause(format!("Invalid number {num}"), cause))
+        })
+        .try_collect()
+}
+
+/// Like `ints`, but the line must have exactly `N` of them
+pub fn ints_array<T, const N: usize>(line: &str) -> AoCResult<[T; N]>
+where
+    T: FromStr,
+    T::Err: Into<Box<dyn Error>>,
+{
+    ints(line)?.try_into().map_err(|nums: Vec<T>| {
+        AoCError::new(format!("Expected {N} numbers but found {}", nums.len()))
+    })
+}
+
+pub fn words(line: &str) -> impl Iterator<Item = &str> {
+    line.split_whitespace()
+}
+
+/// Splits the line in two at the first `sep`
+pub fn split_pair<'a>(line: &'a str, sep: &str) -> AoCResult<(&'a str, &'a str)> {
+    line.split_once(sep)
+        .ok_or_else(|| AoCError::new(format!("Expected a {sep:?} in the line")))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{int_strs, ints, ints_array, split_pair};
+
+    #[test]
+    fn test_int_strs() {
+        let ret: Vec<_> = int_strs("Button A: X+94, Y+34").collect();
+        assert_eq!(ret, ["94", "34"]);
+    }
+
+    #[test]
+    fn test_negatives() {
+        assert_eq!(ints::<i32>("-7 and 8").unwrap(), [-7, 8]);
+        assert_eq!(ints::<i32>("p=0,4 v=-5,-3").unwrap(), [0, 4, -5, -3]);
+        assert_eq!(ints::<i32>("1, -2").unwrap(), [1, -2]);
+        assert_eq!(ints::<i32>("x=-5").unwrap(), [-5]);
+    }
+
+    #[test]
+    fn test_ranges_arent_negative() {
+        assert_eq!(ints::<i32>("1-3").unwrap(), [1, 3]);
+        assert_eq!(ints::<i32>("10-13 a").unwrap(), [10, 13]);
+    }
+
+    #[test]
+    fn test_bad_number() {
+        let err = ints::<u8>("1 300").unwrap_err().to_string();
+        assert!(err.contains("Invalid number 300"), "{err}");
+    }
+
+    #[test]
+    fn test_ints_array() {
+        assert_eq!(ints_array::<u32, 3>("1x2x3").unwrap(), [1, 2, 3]);
+        let err = ints_array::<u32, 3>("1x2").unwrap_err().to_string();
+        assert_eq!(err, "Expected 3 numbers but found 2");
+        let err = ints_array::<u32, 1>("1x2").unwrap_err().to_string();
+        assert_eq!(err, "Expected 1 numbers but found 2");
+    }
+
+    #[test]
+    fn test_split_pair() {
+        assert_eq!(split_pair("a -> b -> c", " -> ").unwrap(), ("a", "b -> c"));
+        assert!(split_pair("a b", " -> ").is_err());
+    }
+}
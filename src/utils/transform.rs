@@ -0,0 +1,419 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::fmt::Display;
+use std::ops::Deref;
+
+use crate::{CommonGrid, Coord2D, Direction, DisplayGrid, FlatGrid};
+
+/// One of the 8 ways to turn a rectangle over onto itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    Identity,
+    /// Clockwise
+    Rotate90,
+    Rotate180,
+    /// Anticlockwise
+    Rotate270,
+    /// Left to right
+    FlipHorizontal,
+    /// Upside down
+    FlipVertical,
+    /// Swaps x and y
+    Transpose,
+    /// Swaps x and y along the other diagonal
+    AntiTranspose,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 8] = [
+        Self::Identity,
+        Self::Rotate90,
+        Self::Rotate180,
+        Self::Rotate270,
+        Self::FlipHorizontal,
+        Self::FlipVertical,
+        Self::Transpose,
+        Self::AntiTranspose,
+    ];
+
+    /// The rotation that turns `direction` into north, so that eg anything
+    /// that tilts a grid northwards can tilt it any way
+    pub const fn facing(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Self::Identity,
+            Direction::East => Self::Rotate270,
+            Direction::South => Self::Rotate180,
+            Direction::West => Self::Rotate90,
+        }
+    }
+
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::Rotate90 => Self::Rotate270,
+            Self::Rotate270 => Self::Rotate90,
+            other => other,
+        }
+    }
+
+    pub const fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Self::Rotate90 | Self::Rotate270 | Self::Transpose | Self::AntiTranspose
+        )
+    }
+
+    /// How big a `width` x `height` grid is once it's been transformed
+    pub const fn size(self, width: i32, height: i32) -> (i32, i32) {
+        if self.swaps_axes() {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    /// Where `coord` in a `width` x `height` grid starting at (0, 0) ends up
+    pub fn apply(self, coord: Coord2D, width: i32, height: i32) -> Coord2D {
+        let Coord2D { x, y } = coord;
+        let (x, y) = match self {
+            Self::Identity => (x, y),
+            Self::Rotate90 => (height - 1 - y, x),
+            Self::Rotate180 => (width - 1 - x, height - 1 - y),
+            Self::Rotate270 => (y, width - 1 - x),
+            Self::FlipHorizontal => (width - 1 - x, y),
+            Self::FlipVertical => (x, height - 1 - y),
+            Self::Transpose => (y, x),
+            Self::AntiTranspose => (height - 1 - y, width - 1 - x),
+        };
+        Coord2D { x, y }
+    }
+}
+
+fn grid_size<G, Item>(grid: &G) -> (Coord2D, i32, i32)
+where
+    G: CommonGrid<Coord2D, Item> + ?Sized,
+{
+    if grid.is_empty() {
+        return (Coord2D { x: 0, y: 0 }, 0, 0);
+    }
+    let min = grid.min_key();
+    let max = grid.max_key();
+    (min, max.x - min.x + 1, max.y - min.y + 1)
+}
+
+/// Rotating and flipping copies of grids
+pub trait TransformGrid<Item: Clone>: FlatGrid<Coord2D, Item> {
+    /// A copy of the grid with `symmetry` applied. The copy always starts at
+    /// (0, 0), and any gaps in the original are left as gaps.
+    fn transform(&self, symmetry: Symmetry) -> Self {
+        let (min, width, height) = grid_size(self);
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| Coord2D { x, y }))
+            .filter_map(|coord| {
+                self.get(&(coord + min))
+                    .map(|item| (symmetry.apply(coord, width, height), item.clone()))
+            })
+            .collect()
+    }
+
+    /// Turns the grid clockwise `turns` times
+    fn rotate(&self, turns: i32) -> Self {
+        self.transform(match turns.rem_euclid(4) {
+            0 => Symmetry::Identity,
+            1 => Symmetry::Rotate90,
+            2 => Symmetry::Rotate180,
+            _ => Symmetry::Rotate270,
+        })
+    }
+
+    fn flip_horizontal(&self) -> Self {
+        self.transform(Symmetry::FlipHorizontal)
+    }
+
+    fn flip_vertical(&self) -> Self {
+        self.transform(Symmetry::FlipVertical)
+    }
+
+    fn transpose(&self) -> Self {
+        self.transform(Symmetry::Transpose)
+    }
+
+    /// Every way the grid can be rotated and flipped, eg for matching it
+    /// against a pattern
+    fn symmetries(&self) -> impl Iterator<Item = Self> {
+        Symmetry::ALL
+            .into_iter()
+            .map(|symmetry| self.transform(symmetry))
+    }
+}
+
+impl<G, Item> TransformGrid<Item> for G
+where
+    G: FlatGrid<Coord2D, Item>,
+    Item: Clone,
+{
+}
+
+/// Looking at parts of a grid without copying anything
+pub trait GridViews<Item>: CommonGrid<Coord2D, Item> {
+    /// Everything in row `y`, from left to right
+    fn row<'a>(&'a self, y: i32) -> impl Iterator<Item = (Coord2D, &'a Item)>
+    where
+        Item: 'a,
+    {
+        let min = self.min_key().x;
+        let max = self.max_key().x;
+        self.rect((min, y).into(), (max, y).into())
+    }
+
+    /// Everything in column `x`, from top to bottom
+    fn column<'a>(&'a self, x: i32) -> impl Iterator<Item = (Coord2D, &'a Item)>
+    where
+        Item: 'a,
+    {
+        let min = self.min_key().y;
+        let max = self.max_key().y;
+        self.rect((x, min).into(), (x, max).into())
+    }
+
+    /// Everything between `min` and `max` inclusive, row by row. Anything
+    /// that isn't in the grid is skipped.
+    fn rect<'a>(&'a self, min: Coord2D, max: Coord2D) -> impl Iterator<Item = (Coord2D, &'a Item)>
+    where
+        Item: 'a,
+    {
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| Coord2D { x, y }))
+            .filter_map(|coord| self.get(&coord).map(|item| (coord, item)))
+    }
+
+    /// Treats the grid as if `symmetry` had been applied to it, without
+    /// moving or copying anything. The view can't be changed, so it's only a
+    /// `ReadOnlyGrid`.
+    fn view(&self, symmetry: Symmetry) -> TransformedGrid<&Self>
+    where
+        Self: Sized,
+    {
+        TransformedGrid::new(self, symmetry)
+    }
+
+    /// Like `view`, but changes made through the view change the grid
+    fn view_mut(&mut self, symmetry: Symmetry) -> TransformedGrid<&mut Self>
+    where
+        Self: Sized,
+    {
+        TransformedGrid::new(self, symmetry)
+    }
+}
+
+impl<G, Item> GridViews<Item> for G where G: CommonGrid<Coord2D, Item> {}
+
+/// See `GridViews::view` and `GridViews::view_mut`. `R` is either `&G` or
+/// `&mut G`.
+pub struct TransformedGrid<R> {
+    grid: R,
+    symmetry: Symmetry,
+    /// Where the grid actually starts
+    min: Coord2D,
+    /// The size of the grid before it's transformed
+    width: i32,
+    height: i32,
+}
+
+impl<R> TransformedGrid<R> {
+    fn new<G, Item>(grid: R, symmetry: Symmetry) -> Self
+    where
+        R: Deref<Target = G>,
+        G: CommonGrid<Coord2D, Item>,
+    {
+        let (min, width, height) = grid_size(&*grid);
+        Self {
+            grid,
+            symmetry,
+            min,
+            width,
+            height,
+        }
+    }
+
+    /// Where something in the view is in the real grid
+    fn to_grid(&self, coord: &Coord2D) -> Coord2D {
+        let (width, height) = self.symmetry.size(self.width, self.height);
+        self.symmetry.inverse().apply(*coord, width, height) + self.min
+    }
+
+    fn view_max_key(&self) -> Coord2D {
+        let (width, height) = self.symmetry.size(self.width, self.height);
+        (width - 1, height - 1).into()
+    }
+}
+
+/// The parts of `CommonGrid` that only look at the grid, for views that
+/// can't be changed
+pub trait ReadOnlyGrid<Item> {
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool;
+
+    fn get(&self, k: &Coord2D) -> Option<&Item>;
+
+    fn min_key(&self) -> Coord2D;
+    fn max_key(&self) -> Coord2D;
+}
+
+impl<G, Item> ReadOnlyGrid<Item> for TransformedGrid<&G>
+where
+    G: CommonGrid<Coord2D, Item>,
+{
+    fn len(&self) -> usize {
+        self.grid.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.grid.is_empty()
+    }
+
+    fn get(&self, k: &Coord2D) -> Option<&Item> {
+        self.grid.get(&self.to_grid(k))
+    }
+
+    fn min_key(&self) -> Coord2D {
+        (0, 0).into()
+    }
+
+    fn max_key(&self) -> Coord2D {
+        self.view_max_key()
+    }
+}
+
+impl<G, Item> CommonGrid<Coord2D, Item> for TransformedGrid<&mut G>
+where
+    G: CommonGrid<Coord2D, Item>,
+{
+    fn len(&self) -> usize {
+        self.grid.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.grid.is_empty()
+    }
+
+    fn get(&self, k: &Coord2D) -> Option<&Item> {
+        self.grid.get(&self.to_grid(k))
+    }
+
+    fn get_mut(&mut self, k: &Coord2D) -> Option<&mut Item> {
+        let k = self.to_grid(k);
+        self.grid.get_mut(&k)
+    }
+
+    fn set(&mut self, k: Coord2D, v: Item) -> Option<Item> {
+        let k = self.to_grid(&k);
+        self.grid.set(k, v)
+    }
+
+    fn get_or_set(&mut self, k: &Coord2D, default: Item) -> &Item {
+        let k = self.to_grid(k);
+        self.grid.get_or_set(&k, default)
+    }
+
+    fn min_key(&self) -> Coord2D {
+        (0, 0).into()
+    }
+
+    fn max_key(&self) -> Coord2D {
+        self.view_max_key()
+    }
+}
+
+impl<G, Item> DisplayGrid<Coord2D, Item> for TransformedGrid<&mut G>
+where
+    G: DisplayGrid<Coord2D, Item>,
+{
+    fn get_for_display(&self, key: &Coord2D) -> Option<&dyn Display> {
+        self.grid.get_for_display(&self.to_grid(key))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GridViews, ReadOnlyGrid, Symmetry, TransformGrid};
+    use crate::utils::densegrid::test::make_grid;
+    use crate::{CommonGrid, Coord2D};
+
+    fn rows(grid: &impl CommonGrid<Coord2D, u32>) -> Vec<Vec<u32>> {
+        let max = grid.max_key();
+        (0..=max.y)
+            .map(|y| {
+                (0..=max.x)
+                    .map(|x| *grid.get(&(x, y).into()).unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let grid = make_grid();
+        for symmetry in Symmetry::ALL {
+            let (width, height) = symmetry.size(3, 2);
+            for (coord, _) in grid.iter() {
+                let moved = symmetry.apply(coord, 3, 2);
+                assert_eq!(
+                    symmetry.inverse().apply(moved, width, height),
+                    coord,
+                    "{symmetry:?}"
+                );
+            }
+            let ret = grid.transform(symmetry).transform(symmetry.inverse());
+            assert_eq!(rows(&ret), rows(&grid), "{symmetry:?}");
+        }
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = make_grid();
+        assert_eq!(
+            rows(&grid.transform(Symmetry::Rotate90)),
+            vec![vec![4, 1], vec![5, 2], vec![6, 3]]
+        );
+        assert_eq!(
+            rows(&grid.transform(Symmetry::Rotate270)),
+            vec![vec![3, 6], vec![2, 5], vec![1, 4]]
+        );
+        assert_eq!(
+            rows(&grid.transform(Symmetry::AntiTranspose)),
+            vec![vec![6, 3], vec![5, 2], vec![4, 1]]
+        );
+    }
+
+    #[test]
+    fn test_view_matches_transform() {
+        let grid = make_grid();
+        for symmetry in Symmetry::ALL {
+            let view = grid.view(symmetry);
+            let transformed = grid.transform(symmetry);
+            assert_eq!(view.len(), transformed.len(), "{symmetry:?}");
+            assert_eq!(view.max_key(), transformed.max_key(), "{symmetry:?}");
+            for (coord, item) in transformed.iter() {
+                assert_eq!(view.get(&coord), Some(item), "{symmetry:?} {coord}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_view_mut() {
+        let mut grid = make_grid();
+        let mut view = grid.view_mut(Symmetry::Rotate90);
+        assert_eq!(view.max_key(), (1, 2).into());
+        assert_eq!(view.get(&(0, 0).into()), Some(&4));
+        assert_eq!(view.set((1, 2).into(), 20), Some(3));
+        assert_eq!(view.get(&(2, 0).into()), None);
+        assert_eq!(rows(&grid), vec![vec![1, 2, 20], vec![4, 5, 6]]);
+    }
+}
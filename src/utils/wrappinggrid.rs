@@ -0,0 +1,243 @@
+// Copyright (c) 2025 Lexi Robinson
+//
+// Licensed under the EUPL, Version 1.2
+//
+// You may not use this work except in compliance with the Licence.
+// You should have received a copy of the Licence along with this work. If not, see:
+// <https://joinup.ec.europa.eu/collection/eupl/eupl-text-eupl-12>.
+// See the Licence for the specific language governing permissions and limitations under the Licence.
+
+use std::fmt::{Debug, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::{CharGrid, CommonGrid, Connectivity, Coord2D, DenseGrid, DisplayGrid, FlatGrid};
+
+/// A grid where going off one edge brings you back on the opposite one, so
+/// every coordinate is valid
+#[derive(Debug, Clone)]
+pub struct WrappingGrid<Item> {
+    grid: DenseGrid<Item>,
+}
+
+impl<Item> WrappingGrid<Item> {
+    pub fn new_with_initialiser<F: Fn() -> Item>(width: u32, height: u32, init: F) -> Self {
+        DenseGrid::new_with_initialiser(width, height, init).into()
+    }
+
+    pub fn width(&self) -> u32 {
+        self.grid.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.grid.height
+    }
+
+    /// Where `coord` actually is in the grid
+    pub fn wrap(&self, coord: &Coord2D) -> Coord2D {
+        Coord2D {
+            x: coord.x.rem_euclid(self.grid.width as i32),
+            y: coord.y.rem_euclid(self.grid.height as i32),
+        }
+    }
+
+    pub fn get_neighbour_coords(
+        &self,
+        coord: Coord2D,
+        diagonal: bool,
+    ) -> impl Iterator<Item = Coord2D> + '_ {
+        let connectivity = if diagonal {
+            Connectivity::Diagonal
+        } else {
+            Connectivity::Orthogonal
+        };
+        connectivity
+            .neighbours(coord)
+            .map(move |neighbour| self.wrap(&neighbour))
+    }
+
+    pub fn get_neighbours(
+        &self,
+        coord: Coord2D,
+        diagonal: bool,
+    ) -> impl Iterator<Item = (Coord2D, &Item)> + '_ {
+        self.get_neighbour_coords(coord, diagonal)
+            .map(move |target| (target, &self[target]))
+    }
+
+    pub fn find<P>(&self, predicate: P) -> Option<Coord2D>
+    where
+        P: FnMut(&(Coord2D, &Item)) -> bool,
+    {
+        self.grid.find(predicate)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord2D, &Item)> {
+        self.grid.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord2D, &mut Item)> {
+        self.grid.iter_mut()
+    }
+}
+
+impl<Item: Clone> WrappingGrid<Item> {
+    pub fn new_filled(width: u32, height: u32, default: Item) -> Self {
+        DenseGrid::new_filled(width, height, default).into()
+    }
+
+    pub fn fill(&mut self, value: Item) {
+        self.grid.fill(value);
+    }
+}
+
+impl<Item: Default + Debug> WrappingGrid<Item> {
+    pub fn new(width: u32, height: u32) -> Self {
+        DenseGrid::new(width, height).into()
+    }
+}
+
+impl<Item> From<DenseGrid<Item>> for WrappingGrid<Item> {
+    fn from(grid: DenseGrid<Item>) -> Self {
+        assert!(
+            grid.width > 0 && grid.height > 0,
+            "Can't wrap an empty grid"
+        );
+        Self { grid }
+    }
+}
+
+impl<Item> Index<Coord2D> for WrappingGrid<Item> {
+    type Output = Item;
+
+    fn index(&self, index: Coord2D) -> &Self::Output {
+        &self.grid[self.wrap(&index)]
+    }
+}
+
+impl<Item> IndexMut<Coord2D> for WrappingGrid<Item> {
+    fn index_mut(&mut self, index: Coord2D) -> &mut Self::Output {
+        let index = self.wrap(&index);
+        &mut self.grid[index]
+    }
+}
+
+impl<Item: Display> Display for WrappingGrid<Item> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.do_fmt(f)
+    }
+}
+
+impl<Item> FromIterator<(Coord2D, Item)> for WrappingGrid<Item> {
+    fn from_iter<T: IntoIterator<Item = (Coord2D, Item)>>(iter: T) -> Self {
+        DenseGrid::from_iter(iter).into()
+    }
+}
+
+impl<Item> IntoIterator for WrappingGrid<Item> {
+    type Item = (Coord2D, Item);
+    type IntoIter = crate::utils::densegrid::IntoIter<Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.grid.into_iter()
+    }
+}
+
+impl<Item> CommonGrid<Coord2D, Item> for WrappingGrid<Item> {
+    fn len(&self) -> usize {
+        self.grid.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.grid.is_empty()
+    }
+
+    fn get(&self, k: &Coord2D) -> Option<&Item> {
+        self.grid.get(&self.wrap(k))
+    }
+
+    fn get_mut(&mut self, k: &Coord2D) -> Option<&mut Item> {
+        let k = self.wrap(k);
+        self.grid.get_mut(&k)
+    }
+
+    fn set(&mut self, k: Coord2D, v: Item) -> Option<Item> {
+        let k = self.wrap(&k);
+        self.grid.set(k, v)
+    }
+
+    /// Every cell always has a value, so `default` is never used
+    fn get_or_set(&mut self, k: &Coord2D, _default: Item) -> &Item {
+        &self[*k]
+    }
+
+    fn max_key(&self) -> Coord2D {
+        self.grid.max_key()
+    }
+
+    fn min_key(&self) -> Coord2D {
+        self.grid.min_key()
+    }
+}
+
+impl<Item> FlatGrid<Coord2D, Item> for WrappingGrid<Item> {
+    fn new_from_lines<Iter, Inner>(data: Iter) -> Self
+    where
+        Inner: IntoIterator<Item = Item>,
+        Iter: Iterator<Item = Inner>,
+    {
+        DenseGrid::new_from_lines(data).into()
+    }
+}
+
+impl<Item: TryFrom<char>> CharGrid<Coord2D, Item> for WrappingGrid<Item> {}
+
+impl<Item: Display> DisplayGrid<Coord2D, Item> for WrappingGrid<Item> {
+    fn get_for_display(&self, key: &Coord2D) -> Option<&dyn Display> {
+        self.get(key).map(|i| i as &dyn Display)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::WrappingGrid;
+    use crate::utils::densegrid::test;
+    use crate::{CommonGrid, Coord2D};
+
+    fn make_grid() -> WrappingGrid<u32> {
+        test::make_grid().into()
+    }
+
+    #[test]
+    fn test_wrap() {
+        let grid = make_grid();
+        let wrap = |x, y| grid.wrap(&Coord2D { x, y });
+        assert_eq!(wrap(1, 1), (1, 1).into());
+        assert_eq!(wrap(3, 2), (0, 0).into());
+        assert_eq!(wrap(-1, -1), (2, 1).into());
+        assert_eq!(wrap(-3, -2), (0, 0).into());
+        assert_eq!(wrap(-4, -3), (2, 1).into());
+        assert_eq!(wrap(-302, 7), (1, 1).into());
+    }
+
+    #[test]
+    fn test_get_and_set() {
+        let mut grid = make_grid();
+        assert_eq!(grid.get(&(-1, 0).into()), Some(&3));
+        assert_eq!(grid[Coord2D { x: 4, y: -1 }], 5);
+        assert_eq!(grid.set((-3, -1).into(), 10), Some(4));
+        assert_eq!(grid[Coord2D { x: 0, y: 1 }], 10);
+        assert_eq!(grid.get_or_set(&(5, 5).into(), 20), &6);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = make_grid();
+        let mut ret: Vec<_> = grid
+            .get_neighbours((0, 0).into(), false)
+            .map(|(_, item)| *item)
+            .collect();
+        ret.sort();
+        assert_eq!(ret, vec![2, 3, 4, 4]);
+        assert_eq!(grid.get_neighbour_coords((0, 0).into(), true).count(), 8);
+    }
+}